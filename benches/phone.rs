@@ -0,0 +1,114 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use yansongda_utils::phone;
+
+const NUMBERS: [&str; 8] = [
+    "13800138000",
+    "+8613800138000",
+    "01012345678-1234",
+    "075512345678",
+    "10086",
+    "95588",
+    "008512345678",
+    "not a phone number",
+];
+
+fn long_input() -> String {
+    "1".repeat(10_000)
+}
+
+fn bench_is_mobile(c: &mut Criterion) {
+    let long = long_input();
+
+    c.bench_function("is_mobile", |b| {
+        b.iter(|| {
+            for number in NUMBERS {
+                black_box(phone::is_mobile(black_box(number)));
+            }
+        })
+    });
+    c.bench_function("is_mobile long input", |b| {
+        b.iter(|| phone::is_mobile(black_box(&long)))
+    });
+}
+
+fn bench_is_phone(c: &mut Criterion) {
+    let long = long_input();
+
+    c.bench_function("is_phone", |b| {
+        b.iter(|| {
+            for number in NUMBERS {
+                black_box(phone::is_phone(black_box(number)));
+            }
+        })
+    });
+    c.bench_function("is_phone long input", |b| {
+        b.iter(|| phone::is_phone(black_box(&long)))
+    });
+}
+
+fn is_phone_naive(number: &str) -> bool {
+    phone::is_mobile(number)
+        || phone::is_telephone(number)
+        || phone::is_service(number)
+        || phone::is_idd(number)
+}
+
+fn bench_is_phone_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_phone order");
+
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            for number in NUMBERS {
+                black_box(is_phone_naive(black_box(number)));
+            }
+        })
+    });
+    group.bench_function("length dispatch", |b| {
+        b.iter(|| {
+            for number in NUMBERS {
+                black_box(phone::is_phone(black_box(number)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_to_standard_format(c: &mut Criterion) {
+    let long = long_input();
+
+    c.bench_function("to_standard_format", |b| {
+        b.iter(|| {
+            for number in NUMBERS {
+                black_box(phone::to_standard_format(black_box(number)));
+            }
+        })
+    });
+    c.bench_function("to_standard_format long input", |b| {
+        b.iter(|| black_box(phone::to_standard_format(black_box(&long))))
+    });
+}
+
+fn bench_classify(c: &mut Criterion) {
+    let long = long_input();
+
+    c.bench_function("classify", |b| {
+        b.iter(|| {
+            for number in NUMBERS {
+                black_box(phone::classify(black_box(number)));
+            }
+        })
+    });
+    c.bench_function("classify long input", |b| {
+        b.iter(|| phone::classify(black_box(&long)))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_is_mobile,
+    bench_is_phone,
+    bench_is_phone_order,
+    bench_to_standard_format,
+    bench_classify
+);
+criterion_main!(benches);
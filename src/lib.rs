@@ -9,6 +9,21 @@
 //!
 //! ## `phone`
 //! 电话号码相关的工具函数
+//!
+//! ## `wasm`
+//! 通过 `wasm-bindgen` 导出电话号码相关的工具函数，以便在浏览器中使用
+//!
+//! ## `csv`
+//! 流式校验 CSV 文件中的电话号码列
+//!
+//! ## `loose`
+//! 手机号码不校验号段是否已分配，任意 `1` 开头的 11 位号码均认为是手机号码（物联网号段除外）
+//!
+//! 注意该 feature 不是叠加的：依赖图中任意一处启用后，所有使用本 crate 的代码都会受到影响。
+//! 仅需单次宽松校验时，请使用 `phone::PhoneValidator::strictness(phone::Strictness::Loose)`
+//!
+//! ## `http`
+//! 通过 HTTP 接口异步查询号段信息
 
 #![forbid(unsafe_code)]
 
@@ -16,3 +31,5 @@
 pub mod macros;
 #[cfg(feature = "phone")]
 pub mod phone;
+#[cfg(feature = "wasm")]
+pub mod wasm;
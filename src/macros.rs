@@ -1,9 +1,159 @@
 //! 常用宏
 
+/// 只编译一次的正则表达式，`$re` 可以是字面量或常量
 #[macro_export]
 macro_rules! regex {
-    ($re:literal $(,)?) => {{
+    ($re:expr $(,)?) => {{
         static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
         RE.get_or_init(|| regex::Regex::new($re).unwrap())
     }};
 }
+
+/// 只编译一次的正则表达式，编译失败时返回错误而不是 panic
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::try_regex;
+///
+/// assert!(try_regex!(r"^\d+$").is_ok());
+/// assert!(try_regex!(r"^(\d+$").is_err());
+/// ```
+#[macro_export]
+macro_rules! try_regex {
+    ($re:expr $(,)?) => {{
+        static RE: std::sync::OnceLock<Result<regex::Regex, regex::Error>> =
+            std::sync::OnceLock::new();
+        RE.get_or_init(|| regex::Regex::new($re)).clone()
+    }};
+}
+
+/// 编译期校验的手机号码字面量，不合法的号码将导致编译失败
+///
+/// 使用 [`is_mobile_fast`](crate::macros::is_mobile_fast) 校验，仅校验号码格式，不校验号段是否已分配
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::mobile;
+///
+/// let number: &'static str = mobile!("13800138000");
+///
+/// assert_eq!("13800138000", number);
+/// ```
+///
+/// ```compile_fail
+/// use yansongda_utils::mobile;
+///
+/// let number = mobile!("12345");
+/// ```
+#[macro_export]
+macro_rules! mobile {
+    ($number:literal $(,)?) => {{
+        const _: () = assert!(
+            $crate::macros::is_mobile_fast($number),
+            "invalid mobile number literal"
+        );
+
+        $number
+    }};
+}
+
+/// 不依赖正则的手机号码校验，可在常量上下文中使用
+///
+/// 仅校验号码格式，即 `^(\+)?(86)?(0)?1[3-9]\d{9}$`；不校验号段是否已分配，
+/// 也不受 `phone::set_mobile_second_digits` 影响，因此可能与 `phone::is_mobile` 的结果不一致
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::macros::is_mobile_fast;
+///
+/// // 154 号段尚未分配，但格式合法
+/// assert!(is_mobile_fast("15400000000"));
+/// # #[cfg(feature = "phone")]
+/// # {
+/// use yansongda_utils::phone::{PhoneValidator, Strictness};
+///
+/// let validator = PhoneValidator::new().strictness(Strictness::Standard);
+///
+/// assert!(!validator.is_mobile("15400000000"));
+/// # }
+/// ```
+pub const fn is_mobile_fast(number: &str) -> bool {
+    let bytes = number.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+
+    if i < len && bytes[i] == b'+' {
+        i += 1;
+    }
+
+    if i + 1 < len && bytes[i] == b'8' && bytes[i + 1] == b'6' {
+        i += 2;
+    }
+
+    if i < len && bytes[i] == b'0' {
+        i += 1;
+    }
+
+    if len - i != 11 || bytes[i] != b'1' || bytes[i + 1] < b'3' || bytes[i + 1] > b'9' {
+        return false;
+    }
+
+    i += 2;
+    while i < len {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_mobile_fast() {
+        let re = regex!(r"^(\+)?(86)?(0)?1[3-9]\d{9}$");
+
+        for number in [
+            "13800138000",
+            "013800138000",
+            "8613800138000",
+            "+8613800138000",
+            "+86013800138000",
+            "+13800138000",
+            "12800138000",
+            "1380013800",
+            "138001380000",
+            "1380013800a",
+            "01012345678",
+            "86",
+            "+",
+            "",
+        ] {
+            assert_eq!(re.is_match(number), is_mobile_fast(number), "{}", number);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::invalid_regex)]
+    fn test_try_regex() {
+        assert!(try_regex!(r"^\d+$").unwrap().is_match("123"));
+        assert!(matches!(
+            try_regex!(r"^(\d+$"),
+            Err(regex::Error::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_mobile() {
+        assert_eq!("13800138000", mobile!("13800138000"));
+        assert_eq!("+8613800138000", mobile!("+8613800138000",));
+    }
+}
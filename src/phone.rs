@@ -13,17 +13,84 @@
 //! assert!(phone::is_service("12345678"));
 //! // 是否是长途
 //! assert!(phone::is_idd("0012345678"));
+//!
+//! // 解析一次，多次查询
+//! let number = phone::PhoneNumber::parse("+8613800138000").unwrap();
+//! assert_eq!("13800138000", number.number());
+//! assert_eq!("1380013", number.segment());
 //! ```
 
-use std::fmt::Formatter;
+use std::borrow::Cow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering as AtomicOrdering};
 
 use crate::regex;
-use serde::de::{Error, Visitor};
+use regex::Regex;
+use serde::de::{Error, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+mod area_code;
+mod country_code;
+mod error;
+#[cfg(feature = "http")]
+mod http;
+mod mobile_segment;
+mod number;
+mod provider;
+mod region;
+mod service_code;
+mod short_code;
+
+pub use area_code::City;
+pub use country_code::Country;
+pub use error::{PhoneError, ValidationError};
+#[cfg(feature = "http")]
+pub use http::HttpProvider;
+pub use number::{PhoneNumber, PhoneNumberBuilder};
+pub use provider::{
+    lookup_with, lookup_with_async, AsyncSegmentProvider, EmbeddedProvider, SegmentInfo,
+    SegmentProvider,
+};
+pub use region::{classify_region, Region};
+
 /// 电话类型
+///
+/// 自 1.1.0 起标记为 `#[non_exhaustive]`，以便后续新增类型时不会造成破坏性变更，
+/// 因此在 crate 外部 `match` 时需要提供 `_` 分支
+///
+/// ```
+/// use yansongda_utils::phone::PhoneType;
+///
+/// let name = match PhoneType::Mobile {
+///     PhoneType::Mobile => "手机",
+///     PhoneType::Tel => "固定电话",
+///     _ => "其它",
+/// };
+///
+/// assert_eq!("手机", name);
+/// ```
+///
+/// ```compile_fail
+/// use yansongda_utils::phone::PhoneType;
+///
+/// match PhoneType::Mobile {
+///     PhoneType::Tel => {}
+///     PhoneType::Mobile => {}
+///     PhoneType::Idd => {}
+///     PhoneType::Service => {}
+///     PhoneType::Iot => {}
+///     PhoneType::Emergency => {}
+///     PhoneType::Business => {}
+///     PhoneType::Others => {}
+/// }
+/// ```
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[non_exhaustive]
 pub enum PhoneType {
     /// 固定电话
     Tel,
@@ -33,34 +100,104 @@ pub enum PhoneType {
     Idd,
     /// 服务号码
     Service,
+    /// 物联网卡，仅用于数据通信
+    Iot,
+    /// 紧急号码，如 `110`、`119`、`120`
+    Emergency,
+    /// 企业号码，即 `400`、`800` 号码
+    Business,
     /// 其它
     Others,
 }
 
-impl ToString for PhoneType {
-    fn to_string(&self) -> String {
+impl PhoneType {
+    /// 大写形式的类型名称，如 `MOBILE`，与 `to_string()` 一致但无需分配内存
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneType::Tel => "TEL",
+            PhoneType::Mobile => "MOBILE",
+            PhoneType::Idd => "IDD",
+            PhoneType::Service => "SERVICE",
+            PhoneType::Iot => "IOT",
+            PhoneType::Emergency => "EMERGENCY",
+            PhoneType::Business => "BUSINESS",
+            _ => "OTHERS",
+        }
+    }
+
+    /// 小写形式的类型名称，如 `mobile`，适用于 HTTP API 等场景
+    pub fn as_lowercase(&self) -> &'static str {
         match self {
-            PhoneType::Tel => String::from("TEL"),
-            PhoneType::Mobile => String::from("MOBILE"),
-            PhoneType::Idd => String::from("IDD"),
-            PhoneType::Service => String::from("SERVICE"),
-            _ => String::from("OTHERS"),
+            PhoneType::Tel => "tel",
+            PhoneType::Mobile => "mobile",
+            PhoneType::Idd => "idd",
+            PhoneType::Service => "service",
+            PhoneType::Iot => "iot",
+            PhoneType::Emergency => "emergency",
+            PhoneType::Business => "business",
+            _ => "others",
         }
     }
+
+    /// 该类型的号码是否可以直接拨打
+    ///
+    /// 手机、固定电话、服务号码、紧急号码、企业号码及国际长途均可拨打，物联网卡等其它类型不可拨打
+    pub fn is_dialable(&self) -> bool {
+        matches!(
+            self,
+            PhoneType::Mobile
+                | PhoneType::Tel
+                | PhoneType::Service
+                | PhoneType::Emergency
+                | PhoneType::Business
+                | PhoneType::Idd
+        )
+    }
+
+    /// 该类型的号码是否可以从境外拨打
+    ///
+    /// 手机、固定电话可以通过 +86 从境外拨打，国际长途本身即为国际号码；
+    /// 服务号码、紧急号码、企业号码仅限境内拨打
+    pub fn is_internationally_dialable(&self) -> bool {
+        matches!(self, PhoneType::Mobile | PhoneType::Tel | PhoneType::Idd)
+    }
 }
 
-impl From<String> for PhoneType {
-    fn from(v: String) -> Self {
-        match v.to_lowercase().as_str() {
-            "tel" => PhoneType::Tel,
-            "mobile" => PhoneType::Mobile,
-            "idd" => PhoneType::Idd,
-            "service" => PhoneType::Service,
+impl Display for PhoneType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for PhoneType {
+    fn from(v: &str) -> Self {
+        match v {
+            v if v.eq_ignore_ascii_case("tel") => PhoneType::Tel,
+            v if v.eq_ignore_ascii_case("mobile") => PhoneType::Mobile,
+            v if v.eq_ignore_ascii_case("idd") => PhoneType::Idd,
+            v if v.eq_ignore_ascii_case("service") => PhoneType::Service,
+            v if v.eq_ignore_ascii_case("iot") => PhoneType::Iot,
+            v if v.eq_ignore_ascii_case("emergency") => PhoneType::Emergency,
+            v if v.eq_ignore_ascii_case("business") => PhoneType::Business,
             _s => PhoneType::Others,
         }
     }
 }
 
+impl From<String> for PhoneType {
+    fn from(v: String) -> Self {
+        v.as_str().into()
+    }
+}
+
+impl FromStr for PhoneType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
 impl From<PhoneType> for String {
     fn from(v: PhoneType) -> Self {
         v.to_string()
@@ -91,7 +228,7 @@ impl<'de> Visitor<'de> for PhoneTypeVisitor {
     where
         E: Error,
     {
-        Ok(v.to_owned().into())
+        Ok(v.into())
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
@@ -112,7 +249,23 @@ impl<'de> Deserialize<'de> for PhoneType {
 }
 
 /// 电话运营商
-#[derive(Clone, Debug, PartialEq)]
+///
+/// 自 1.1.0 起标记为 `#[non_exhaustive]`，以便后续新增运营商时不会造成破坏性变更，
+/// 因此在 crate 外部 `match` 时需要提供 `_` 分支
+///
+/// ```
+/// use yansongda_utils::phone::MobileVendor;
+///
+/// let name = match MobileVendor::Mobile {
+///     MobileVendor::Mobile => "移动",
+///     MobileVendor::Unicom => "联通",
+///     _ => "其它",
+/// };
+///
+/// assert_eq!("移动", name);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MobileVendor {
     /// 中国移动
     Mobile,
@@ -126,30 +279,90 @@ pub enum MobileVendor {
     Others,
 }
 
-impl ToString for MobileVendor {
-    fn to_string(&self) -> String {
+impl MobileVendor {
+    /// 运营商客服号码，如 `10086`
+    pub fn code(&self) -> &'static str {
+        match self {
+            MobileVendor::Unicom => "10010",
+            MobileVendor::Telecom => "10000",
+            MobileVendor::Mobile => "10086",
+            MobileVendor::Cbn => "10099",
+            _ => "unknown",
+        }
+    }
+
+    /// 按市场份额的排名
+    fn rank(&self) -> u8 {
+        match self {
+            MobileVendor::Mobile => 0,
+            MobileVendor::Unicom => 1,
+            MobileVendor::Telecom => 2,
+            MobileVendor::Cbn => 3,
+            _ => 4,
+        }
+    }
+
+    /// 运营商名称，如 `移动`
+    pub fn name(&self) -> &'static str {
         match self {
-            MobileVendor::Unicom => String::from("10010 联通"),
-            MobileVendor::Telecom => String::from("10000 电信"),
-            MobileVendor::Mobile => String::from("10086 移动"),
-            MobileVendor::Cbn => String::from("10099 广电"),
-            _ => String::from("unknown 未知"),
+            MobileVendor::Unicom => "联通",
+            MobileVendor::Telecom => "电信",
+            MobileVendor::Mobile => "移动",
+            MobileVendor::Cbn => "广电",
+            _ => "未知",
         }
     }
 }
 
-impl From<String> for MobileVendor {
-    fn from(v: String) -> Self {
-        match v.to_lowercase().as_str() {
-            "10010 联通" | "10010" | "unicom" => MobileVendor::Unicom,
-            "10000 电信" | "10000" | "telecom" => MobileVendor::Telecom,
-            "10086 移动" | "10086" | "mobile" => MobileVendor::Mobile,
-            "10099 广电" | "10099" | "cbn" => MobileVendor::Cbn,
+/// 按市场份额排序，即 移动、联通、电信、广电、其它
+impl Ord for MobileVendor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for MobileVendor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for MobileVendor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.code(), self.name())
+    }
+}
+
+impl From<&str> for MobileVendor {
+    fn from(v: &str) -> Self {
+        match v {
+            "10010 联通" | "10010" => MobileVendor::Unicom,
+            "10000 电信" | "10000" => MobileVendor::Telecom,
+            "10086 移动" | "10086" => MobileVendor::Mobile,
+            "10099 广电" | "10099" => MobileVendor::Cbn,
+            v if v.eq_ignore_ascii_case("unicom") => MobileVendor::Unicom,
+            v if v.eq_ignore_ascii_case("telecom") => MobileVendor::Telecom,
+            v if v.eq_ignore_ascii_case("mobile") => MobileVendor::Mobile,
+            v if v.eq_ignore_ascii_case("cbn") => MobileVendor::Cbn,
             _s => MobileVendor::Others,
         }
     }
 }
 
+impl From<String> for MobileVendor {
+    fn from(v: String) -> Self {
+        v.as_str().into()
+    }
+}
+
+impl FromStr for MobileVendor {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
 impl From<MobileVendor> for String {
     fn from(v: MobileVendor) -> Self {
         v.to_string()
@@ -180,7 +393,7 @@ impl<'de> Visitor<'de> for MobileVendorVisitor {
     where
         E: Error,
     {
-        Ok(v.to_owned().into())
+        Ok(v.into())
     }
 
     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
@@ -200,184 +413,2451 @@ impl<'de> Deserialize<'de> for MobileVendor {
     }
 }
 
-/// 给定的号码是否是正常的手机号
-pub fn is_mobile(number: &str) -> bool {
-    regex!(r"^(\+)?(86)?(0)?1[3-9]\d{9}$").is_match(number)
+/// `MobileVendor` 的其它序列化方式，配合 `#[serde(with = "...")]` 使用
+pub mod vendor_serde {
+    /// 将 `MobileVendor` 序列化为 `{ "code": "10086", "name": "移动" }` 的形式
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use yansongda_utils::phone::{vendor_serde, MobileVendor};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Response {
+    ///     #[serde(with = "vendor_serde::object")]
+    ///     vendor: MobileVendor,
+    /// }
+    /// ```
+    pub mod object {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use crate::phone::MobileVendor;
+
+        #[derive(Serialize)]
+        struct VendorObjectRef {
+            code: &'static str,
+            name: &'static str,
+        }
+
+        #[derive(Deserialize)]
+        struct VendorObject {
+            code: String,
+        }
+
+        pub fn serialize<S>(vendor: &MobileVendor, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            VendorObjectRef {
+                code: vendor.code(),
+                name: vendor.name(),
+            }
+            .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<MobileVendor, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            VendorObject::deserialize(deserializer).map(|v| v.code.into())
+        }
+    }
 }
 
-/// 给定的号码是否是正常的座机号(含分机)
-pub fn is_telephone(number: &str) -> bool {
-    regex!(r"^(\+)?(86)?0\d{9,11}([-,]\d{4,7})?$").is_match(number)
+/// 兼容 JSON 字符串及数字形式的电话号码（反）序列化，配合 `#[serde(with = "...")]` 使用
+///
+/// 反序列化时数字会被转换为十进制字符串，并使用 [`is_phone`] 校验
+///
+/// # Example
+///
+/// ```
+/// use serde::Deserialize;
+/// use yansongda_utils::phone;
+///
+/// #[derive(Deserialize)]
+/// struct Request {
+///     #[serde(with = "phone::flexible_serde")]
+///     phone: String,
+/// }
+///
+/// let request: Request = serde_json::from_str(r#"{"phone": 13800138000}"#).unwrap();
+///
+/// assert_eq!("13800138000", request.phone);
+/// ```
+pub mod flexible_serde {
+    use std::fmt::Formatter;
+
+    use serde::de::{Error, Unexpected, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use crate::phone::is_phone;
+
+    struct FlexibleVisitor;
+
+    impl<'de> Visitor<'de> for FlexibleVisitor {
+        type Value = String;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("反序列化失败，值应该为合法的电话号码 string/number.")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_string(v.to_owned())
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            if !is_phone(&v) {
+                return Err(E::invalid_value(Unexpected::Str(&v), &self));
+            }
+
+            Ok(v)
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_string(v.to_string())
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            if v < 0 {
+                return Err(E::invalid_value(Unexpected::Signed(v), &self));
+            }
+
+            self.visit_string(v.to_string())
+        }
+    }
+
+    pub fn serialize<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FlexibleVisitor)
+    }
 }
 
-/// 给定的号码是否是正常的服务号码
-pub fn is_service(number: &str) -> bool {
-    regex!(r"^1\d{7}$|^[1,9]\d{4}$").is_match(number)
+/// 合法的手机号码
+///
+/// 通过 [`MobileNumber::new`]、`parse` 创建或反序列化时会校验号码是否为手机号，
+/// 不合法的号码将直接返回错误，因此可以直接用于请求/响应结构体中
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct MobileNumber(String);
+
+impl MobileNumber {
+    /// 校验并创建手机号码，不是手机号码时返回错误
+    ///
+    /// ```
+    /// use yansongda_utils::phone::MobileNumber;
+    ///
+    /// assert!(MobileNumber::new("13800138000").is_ok());
+    /// assert!(MobileNumber::new("01012345678").is_err());
+    /// ```
+    pub fn new(number: impl Into<String>) -> Result<Self, PhoneError> {
+        let number = number.into();
+
+        if !is_mobile(&number) {
+            return Err(PhoneError::Invalid(number));
+        }
+
+        Ok(MobileNumber(number))
+    }
+
+    /// 原始号码
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 中国标准格式的号码，即不带 +、+86、86、0 等形式
+    pub fn standardized(&self) -> &str {
+        to_standard_format(&self.0)
+    }
+
+    /// 根据号段判断的运营商
+    pub fn vendor(&self) -> MobileVendor {
+        get_vendor(&self.0)
+    }
 }
 
-/// 给定的号码是否是正常的国际长途号码
-pub fn is_idd(number: &str) -> bool {
-    regex!(r"^00\d{8,}$").is_match(number)
+impl FromStr for MobileNumber {
+    type Err = PhoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MobileNumber::new(s)
+    }
 }
 
-/// 给定的号码是否是正常的电话号码
-pub fn is_phone(number: &str) -> bool {
-    is_mobile(number) || is_telephone(number) || is_service(number) || is_idd(number)
+impl Serialize for MobileNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
 }
 
-/// 将号码转换为中国标准格式，即不带 +、+86、86、0 等形式
-/// 如果是国际号码，维持不变
-pub fn to_standard_format(number: &str) -> &str {
-    let mut offset: usize = 0;
+struct MobileNumberVisitor;
 
-    if number[offset..].starts_with(char::from_str("+").unwrap()) {
-        offset += 1;
-    }
+impl<'de> Visitor<'de> for MobileNumberVisitor {
+    type Value = MobileNumber;
 
-    if number[offset..].starts_with("86") {
-        offset += 2;
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("反序列化失败，值应该为合法的手机号码.")
     }
 
-    if is_mobile(&number[offset..]) && number[offset..].starts_with(char::from_str("0").unwrap()) {
-        offset += 1;
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_string(v.to_owned())
     }
 
-    &number[offset..]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if !is_mobile(&v) {
+            return Err(E::invalid_value(Unexpected::Str(&v), &self));
+        }
+
+        Ok(MobileNumber(v))
+    }
 }
 
-/// 获取号码的号段，以便区分运营商，获取号段详细归属地信息
-pub fn get_segment(number: &str) -> (PhoneType, &str) {
-    if is_mobile(number) {
-        return (PhoneType::Mobile, &number[..7]);
+impl<'de> Deserialize<'de> for MobileNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(MobileNumberVisitor)
     }
+}
+
+/// 号码黑名单，如免打扰名单
+///
+/// 号码在存入及查询时都会转换为中国标准格式，
+/// 因此 `+8613800138000` 可以命中已存入的 `13800138000`
+#[derive(Clone, Debug, Default)]
+pub struct BlockList {
+    set: HashSet<String>,
+}
 
-    if is_idd(number) {
-        return (PhoneType::Idd, &number[2..6]);
+impl BlockList {
+    /// 通过号码列表创建黑名单
+    pub fn from_numbers<I, S>(numbers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        BlockList {
+            set: numbers
+                .into_iter()
+                .map(|number| to_standard_format(number.as_ref()).to_owned())
+                .collect(),
+        }
     }
 
-    if is_service(number) {
-        return (PhoneType::Service, number);
+    /// 黑名单中是否包含给定的号码
+    pub fn contains(&self, number: &str) -> bool {
+        self.set.contains(to_standard_format(number))
     }
+}
+
+const MOBILE_PATTERN: &str = r"^(\+\s*)?(86\s*)?(0)?1[0-9]{10}$";
+const TELEPHONE_PATTERN: &str = r"^(\+)?(86)?0\d{9,11}([-,]\d{4,7})?$";
+const SERVICE_PATTERN: &str = r"^1\d{7}$|^[1,9]\d{4}$";
+const IDD_PATTERN: &str = r"^00\d{8,}$";
+const EMERGENCY_PATTERN: &str = r"^(110|112|119|120|122|999)$";
+const BUSINESS_PATTERN: &str = r"^[48]00-?\d{3}-?\d{4}$";
+const IOT_PATTERN: &str = r"^(\+)?(86)?(14[014689]\d{10}|1064\d{9}|1(46|48|72)\d{8})$";
+
+/// 判断号码类型时的优先级，从高到低排列
+const CLASSIFY_ORDER: [PhoneType; 7] = [
+    PhoneType::Mobile,
+    PhoneType::Iot,
+    PhoneType::Idd,
+    PhoneType::Emergency,
+    PhoneType::Business,
+    PhoneType::Service,
+    PhoneType::Tel,
+];
+
+/// 号码校验的严格程度
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strictness {
+    /// 仅校验号码格式，任意 `1` 开头的 11 位号码均认为是手机号码（物联网号段除外）
+    Loose,
+    /// 在格式的基础上，手机号码须为已分配的号段
+    Standard,
+    /// 在 [`Strictness::Standard`] 的基础上，固定电话还须为真实存在的区号，且本地号码长度与区号匹配
+    Strict,
+}
+
+/// 默认为 [`Strictness::Standard`]，启用 `loose` feature 时为 [`Strictness::Loose`]
+impl Default for Strictness {
+    fn default() -> Self {
+        if cfg!(feature = "loose") {
+            return Strictness::Loose;
+        }
 
-    if number.starts_with("010") || number[..2].starts_with("02") {
-        return (PhoneType::Tel, &number[..3]);
+        Strictness::Standard
     }
+}
 
-    (PhoneType::Tel, &number[..4])
+/// 持有已编译正则表达式的号码校验器
+///
+/// 默认的严格程度下与模块中的同名函数行为一致，适合需要依赖注入、控制生命周期的场景；
+/// 可以通过 [`PhoneValidator::strictness`] 调整校验的严格程度
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone::PhoneValidator;
+///
+/// let validator = PhoneValidator::new();
+///
+/// assert!(validator.is_mobile("13800138000"));
+/// assert!(validator.is_phone("01012345678"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PhoneValidator {
+    mobile: Regex,
+    telephone: Regex,
+    service: Regex,
+    idd: Regex,
+    iot: Regex,
+    emergency: Regex,
+    business: Regex,
+    strictness: Strictness,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl PhoneValidator {
+    /// 编译所有的正则表达式
+    ///
+    /// # Panics
+    ///
+    /// 正则表达式编译失败时 panic，如需处理错误请使用 [`PhoneValidator::try_new`]
+    pub fn new() -> Self {
+        Self::try_new().unwrap()
+    }
 
-    #[test]
-    fn test_phone_type_string() {
-        assert_eq!("MOBILE", PhoneType::Mobile.to_string());
-        assert_eq!("TEL", PhoneType::Tel.to_string());
-        assert_eq!("SERVICE", PhoneType::Service.to_string());
-        assert_eq!("IDD", PhoneType::Idd.to_string());
+    /// 编译所有的正则表达式，编译失败时返回错误
+    pub fn try_new() -> Result<Self, regex::Error> {
+        Ok(PhoneValidator {
+            mobile: Regex::new(MOBILE_PATTERN)?,
+            telephone: Regex::new(TELEPHONE_PATTERN)?,
+            service: Regex::new(SERVICE_PATTERN)?,
+            idd: Regex::new(IDD_PATTERN)?,
+            iot: Regex::new(IOT_PATTERN)?,
+            emergency: Regex::new(EMERGENCY_PATTERN)?,
+            business: Regex::new(BUSINESS_PATTERN)?,
+            strictness: Strictness::default(),
+        })
+    }
 
-        assert_eq!(PhoneType::Mobile, "MOBILE".to_string().into());
-        assert_eq!(PhoneType::Tel, "TeL".to_string().into());
-        assert_eq!(PhoneType::Service, "SERViCE".to_string().into());
-        assert_eq!(PhoneType::Idd, "IDD".to_string().into());
+    /// 设置校验的严格程度，默认为 [`Strictness::default`]
+    pub fn strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
     }
 
-    #[test]
-    fn test_phone_type_json() {
-        assert_eq!(
-            PhoneType::Mobile,
-            serde_json::from_str("\"MOBILE\"").unwrap()
-        );
-        assert_eq!(PhoneType::Tel, serde_json::from_str("\"TeL\"").unwrap());
-        assert_eq!(
-            PhoneType::Service,
-            serde_json::from_str("\"SERViCE\"").unwrap()
-        );
-        assert_eq!(PhoneType::Idd, serde_json::from_str("\"IDD\"").unwrap());
+    /// 给定的号码是否是正常的手机号
+    pub fn is_mobile(&self, number: &str) -> bool {
+        is_within_max_input_len(number)
+            && self.mobile.is_match(number)
+            && is_mobile_second_digit_allowed(number)
+            && !is_iot_segment(number)
+            && (self.strictness == Strictness::Loose || is_mobile_segment_allocated(number))
     }
 
-    #[test]
-    fn test_mobile_vendor_string() {
-        assert_eq!("10086 移动", MobileVendor::Mobile.to_string());
-        assert_eq!("10010 联通", MobileVendor::Unicom.to_string());
-        assert_eq!("10000 电信", MobileVendor::Telecom.to_string());
-        assert_eq!("10099 广电", MobileVendor::Cbn.to_string());
+    /// 给定的号码是否是正常的座机号(含分机)
+    pub fn is_telephone(&self, number: &str) -> bool {
+        is_within_max_input_len(number)
+            && self.telephone.is_match(number)
+            && (self.strictness != Strictness::Strict
+                || (is_telephone_strict(number) && is_telephone_length_consistent(number)))
+    }
 
-        assert_eq!(MobileVendor::Mobile, "10086 移动".to_string().into());
-        assert_eq!(MobileVendor::Mobile, "mObIle".to_string().into());
-        assert_eq!(MobileVendor::Unicom, "10010 联通".to_string().into());
-        assert_eq!(MobileVendor::Unicom, "10010".to_string().into());
-        assert_eq!(MobileVendor::Telecom, "10000 电信".to_string().into());
-        assert_eq!(MobileVendor::Telecom, "telecom".to_string().into());
-        assert_eq!(MobileVendor::Cbn, "10099 广电".to_string().into());
-        assert_eq!(MobileVendor::Cbn, "cbn".to_string().into());
+    /// 给定的号码是否是正常的服务号码，包括收录的非紧急短号码
+    pub fn is_service(&self, number: &str) -> bool {
+        is_within_max_input_len(number)
+            && (self.service.is_match(number) || is_service_short_code(number))
     }
 
-    #[test]
-    fn test_mobile_vendor_json() {
-        assert_eq!(
-            MobileVendor::Mobile,
-            serde_json::from_str("\"10086 移动\"").unwrap()
-        );
-        assert_eq!(
-            MobileVendor::Mobile,
-            serde_json::from_str("\"mObIle\"").unwrap()
-        );
-        assert_eq!(
-            MobileVendor::Unicom,
-            serde_json::from_str("\"10010 联通\"").unwrap()
-        );
-        assert_eq!(
-            MobileVendor::Unicom,
-            serde_json::from_str("\"10010\"").unwrap()
-        );
-        assert_eq!(
-            MobileVendor::Telecom,
-            serde_json::from_str("\"10000 电信\"").unwrap()
-        );
-        assert_eq!(
-            MobileVendor::Telecom,
-            serde_json::from_str("\"telecom\"").unwrap()
-        );
-        assert_eq!(
-            MobileVendor::Cbn,
-            serde_json::from_str("\"10099 广电\"").unwrap()
-        );
-        assert_eq!(MobileVendor::Cbn, serde_json::from_str("\"cbn\"").unwrap());
+    /// 给定的号码是否是正常的国际长途号码
+    pub fn is_idd(&self, number: &str) -> bool {
+        is_within_max_input_len(number) && self.idd.is_match(number)
     }
 
-    #[test]
-    fn test_is_mobile() {
-        assert!(is_mobile("13800138000"));
-        assert!(is_mobile("+8613800138000"));
+    /// 给定的号码是否是物联网卡号码
+    pub fn is_iot(&self, number: &str) -> bool {
+        is_within_max_input_len(number) && self.iot.is_match(number)
     }
 
-    #[test]
-    fn test_is_telephone() {
-        assert!(is_telephone("01012345678"));
-        assert!(is_telephone("01012345678-1234"));
-        assert!(is_telephone("01012345678,1234"));
-        assert!(is_telephone("075512345678"));
-        assert!(is_telephone("075512345678-1234"));
-        assert!(is_telephone("075512345678,1234"));
+    /// 给定的号码是否是紧急号码
+    pub fn is_emergency(&self, number: &str) -> bool {
+        is_within_max_input_len(number) && self.emergency.is_match(number)
     }
 
-    #[test]
-    fn test_is_service() {
-        assert!(is_service("10000000"));
-        assert!(is_service("10086"));
-        assert!(is_service("95588"));
+    /// 给定的号码是否是企业号码
+    pub fn is_business(&self, number: &str) -> bool {
+        is_within_max_input_len(number) && self.business.is_match(number)
     }
 
-    #[test]
-    fn test_is_idd() {
-        assert!(is_idd("0012345678"));
-        assert!(is_idd("008512345678"));
+    /// 给定的号码是否是正常的电话号码，与 [`is_phone`] 一样不包括物联网卡、紧急号码及企业号码
+    pub fn is_phone(&self, number: &str) -> bool {
+        self.is_mobile(number)
+            || self.is_telephone(number)
+            || self.is_service(number)
+            || self.is_idd(number)
     }
 
-    #[test]
-    fn test_is_phone() {
+    /// 判断号码类型，优先级与 [`classify`] 相同，均不符合时返回 [`PhoneType::Others`]
+    pub fn classify(&self, number: &str) -> PhoneType {
+        CLASSIFY_ORDER
+            .into_iter()
+            .find(|phone_type| self.is_type(number, phone_type))
+            .unwrap_or(PhoneType::Others)
+    }
+
+    /// 返回给定号码可能属于的所有类型，按与 [`classify`] 相同的优先级排列
+    pub fn classify_all(&self, number: &str) -> Vec<PhoneType> {
+        CLASSIFY_ORDER
+            .into_iter()
+            .filter(|phone_type| self.is_type(number, phone_type))
+            .collect()
+    }
+
+    fn is_type(&self, number: &str, phone_type: &PhoneType) -> bool {
+        match phone_type {
+            PhoneType::Mobile => self.is_mobile(number),
+            PhoneType::Iot => self.is_iot(number),
+            PhoneType::Idd => self.is_idd(number),
+            PhoneType::Emergency => self.is_emergency(number),
+            PhoneType::Business => self.is_business(number),
+            PhoneType::Service => self.is_service(number),
+            PhoneType::Tel => self.is_telephone(number),
+            _ => false,
+        }
+    }
+}
+
+impl Default for PhoneValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 默认的最大输入长度
+pub const DEFAULT_MAX_INPUT_LEN: usize = 64;
+
+static MAX_INPUT_LEN: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_INPUT_LEN);
+
+/// 设置校验函数允许的最大输入长度（字节数），默认为 [`DEFAULT_MAX_INPUT_LEN`]
+///
+/// 超过该长度的输入，所有 `is_*` 校验函数都会直接返回 `false` 而不执行正则匹配，
+/// 以避免校验不可信的超长输入时消耗过多资源。该设置是全局的
+pub fn set_max_input_len(len: usize) {
+    MAX_INPUT_LEN.store(len, AtomicOrdering::Relaxed);
+}
+
+fn is_within_max_input_len(number: &str) -> bool {
+    number.len() <= MAX_INPUT_LEN.load(AtomicOrdering::Relaxed)
+}
+
+/// 默认允许的手机号码第二位数字
+pub const DEFAULT_MOBILE_SECOND_DIGITS: [u8; 7] = [3, 4, 5, 6, 7, 8, 9];
+
+/// 允许的手机号码第二位数字，按位存储，第 n 位为 1 表示允许数字 n
+static MOBILE_SECOND_DIGITS: AtomicU16 = AtomicU16::new(0b11_1111_1000);
+
+/// 设置允许的手机号码第二位数字，默认为 [`DEFAULT_MOBILE_SECOND_DIGITS`]，即 `3-9`
+///
+/// 用于监管部门新放号段时无需等待新版本发布，大于 9 的数字会被忽略。
+/// 该设置是全局的，对 [`is_mobile`] 等所有手机号码校验函数生效
+///
+/// 设置以原子变量存储，可以在任意线程中与校验函数并发调用，
+/// 校验函数读取到的总是某一次完整的设置，不会出现部分生效的情况
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone;
+///
+/// phone::set_mobile_second_digits(&[2, 3, 4, 5, 6, 7, 8, 9]);
+///
+/// assert!(phone::is_mobile("12012345678"));
+/// ```
+pub fn set_mobile_second_digits(digits: &[u8]) {
+    let mask = digits
+        .iter()
+        .filter(|digit| **digit <= 9)
+        .fold(0u16, |mask, digit| mask | (1 << digit));
+
+    MOBILE_SECOND_DIGITS.store(mask, AtomicOrdering::Relaxed);
+}
+
+/// 手机号码的第二位数字是否被允许，调用方需保证号码以 `1` 加 10 位数字结尾
+fn is_mobile_second_digit_allowed(number: &str) -> bool {
+    let digit = number.as_bytes()[number.len() - 10] - b'0';
+
+    MOBILE_SECOND_DIGITS.load(AtomicOrdering::Relaxed) & (1 << digit) != 0
+}
+
+/// 是否是 11 位的物联网号段，调用方需保证号码以 `1` 加 10 位数字结尾
+///
+/// 物联网号段与手机号码格式相同，无论是否启用 `loose` feature 都不认为是手机号码
+fn is_iot_segment(number: &str) -> bool {
+    mobile_segment::IOT_SEGMENTS
+        .binary_search(&safe_slice(number, number.len() - 11, number.len() - 8))
+        .is_ok()
+}
+
+/// 手机号码的号段是否已分配，调用方需保证号码以 `1` 加 10 位数字结尾
+///
+/// 第二位数字不在 [`DEFAULT_MOBILE_SECOND_DIGITS`] 中时，说明是通过 [`set_mobile_second_digits`]
+/// 额外允许的新号段，此时也不校验号段
+fn is_mobile_segment_allocated(number: &str) -> bool {
+    let digit = number.as_bytes()[number.len() - 10] - b'0';
+
+    !DEFAULT_MOBILE_SECOND_DIGITS.contains(&digit)
+        || find_mobile_segment(safe_slice(number, number.len() - 11, number.len())).is_some()
+}
+
+/// 按最长匹配查找 11 位手机号码所属的号段
+fn find_mobile_segment(number: &str) -> Option<&'static (&'static str, MobileVendor)> {
+    [4, 3].into_iter().find_map(|len| {
+        let prefix = safe_slice(number, 0, len);
+
+        mobile_segment::MOBILE_SEGMENTS
+            .binary_search_by(|(segment, _)| segment.cmp(&prefix))
+            .ok()
+            .map(|index| &mobile_segment::MOBILE_SEGMENTS[index])
+    })
+}
+
+/// 给定的号码是否是正常的手机号
+///
+/// 第二位数字默认允许 `3-9`，可以通过 [`set_mobile_second_digits`] 设置；
+/// 号段须为工信部已分配的号段，启用 `loose` feature 时不校验号段；
+/// `+`、`86` 前缀之后的空白字符会被忽略，如 `+ 86 13800138000`，号码中间的空白字符则不允许
+pub fn is_mobile(number: &str) -> bool {
+    is_within_max_input_len(number)
+        && regex!(MOBILE_PATTERN).is_match(number)
+        && is_mobile_second_digit_allowed(number)
+        && !is_iot_segment(number)
+        && (cfg!(feature = "loose") || is_mobile_segment_allocated(number))
+}
+
+/// 给定的号码是否可能是手机号，仅校验长度和字符，不使用正则表达式
+///
+/// 去除 `+`、`86`、`0` 前缀（及前缀之后的空白字符）后为 `1` 开头的 11 位数字即可，适用于批量导入时快速预筛；
+/// 通过预筛的号码仍需使用 [`is_mobile`] 做完整校验
+pub fn is_possible_mobile(number: &str) -> bool {
+    let rest = number.strip_prefix('+').map_or(number, str::trim_start);
+    let rest = rest.strip_prefix("86").map_or(rest, str::trim_start);
+    let rest = rest.strip_prefix('0').unwrap_or(rest);
+
+    rest.len() == 11 && rest.starts_with('1') && rest.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// 给定的号码是否可能是电话号码，仅校验长度和字符，不使用正则表达式
+///
+/// 只含数字、空白字符及 `+`、`-`、`,`，且至少有 3 位数字即可；通过预筛的号码仍需使用 [`is_phone`] 做完整校验
+pub fn is_possible_phone(number: &str) -> bool {
+    is_within_max_input_len(number)
+        && number
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace() || matches!(c, '+' | '-' | ','))
+        && number.bytes().filter(u8::is_ascii_digit).count() >= 3
+}
+
+/// 按指定的宽松程度判断给定的号码是否是正常的手机号
+///
+/// - `allow_country_code`: 是否允许 `+`、`+86`、`86` 国家代码前缀
+/// - `allow_trunk_zero`: 是否允许 `0` 前缀
+///
+/// 两者都为 `true` 时与 [`is_mobile`] 行为一致
+pub fn is_mobile_strict(number: &str, allow_country_code: bool, allow_trunk_zero: bool) -> bool {
+    if !is_within_max_input_len(number) {
+        return false;
+    }
+
+    let mut rest = number;
+
+    if allow_country_code {
+        rest = rest.strip_prefix('+').map_or(rest, str::trim_start);
+        rest = rest.strip_prefix("86").map_or(rest, str::trim_start);
+    }
+
+    if allow_trunk_zero {
+        rest = rest.strip_prefix('0').unwrap_or(rest);
+    }
+
+    regex!(r"^1[0-9]{10}$").is_match(rest)
+        && is_mobile_second_digit_allowed(rest)
+        && !is_iot_segment(rest)
+        && (cfg!(feature = "loose") || is_mobile_segment_allocated(rest))
+}
+
+/// 给定的号码是否是正常的座机号(含分机)
+pub fn is_telephone(number: &str) -> bool {
+    is_within_max_input_len(number) && regex!(TELEPHONE_PATTERN).is_match(number)
+}
+
+/// 严格地判断给定的号码是否是正常的座机号(含分机)
+///
+/// 与 [`is_telephone`] 不同的是，还会校验区号是否为真实存在的中国大陆区号
+pub fn is_telephone_strict(number: &str) -> bool {
+    if !is_telephone(number) {
+        return false;
+    }
+
+    let number = to_standard_format(number);
+    let area_code = safe_slice(number, 0, area_code_len(number));
+
+    area_code_city(area_code).is_some()
+}
+
+/// 给定的号码是否是正常的服务号码
+///
+/// 包括 [`short_code_name`] 中收录的 `114`、`121` 等非紧急短号码，紧急号码见 [`is_emergency`]
+pub fn is_service(number: &str) -> bool {
+    is_within_max_input_len(number)
+        && (regex!(SERVICE_PATTERN).is_match(number) || is_service_short_code(number))
+}
+
+/// 给定的号码是否是正常的服务号码，额外允许带有 1-2 位后缀的 `95`、`96` 企业服务号码
+///
+/// 即 5 至 7 位的 `95xxx`、`96xxx` 号码，如 `95588`、`955801`、`9558012`
+pub fn is_service_extended(number: &str) -> bool {
+    is_service(number)
+        || (is_within_max_input_len(number) && regex!(r"^9[56]\d{3,5}$").is_match(number))
+}
+
+/// 给定的号码是否是正常的服务号码，允许带有 +86、86 的国际形式
+pub fn is_service_intl(number: &str) -> bool {
+    let local = number
+        .strip_prefix("+86")
+        .or_else(|| number.strip_prefix("86"))
+        .unwrap_or(number);
+
+    is_service(local)
+}
+
+/// 给定的号码是否是正常的国际长途号码
+pub fn is_idd(number: &str) -> bool {
+    is_within_max_input_len(number) && regex!(IDD_PATTERN).is_match(number)
+}
+
+/// 给定的号码是否是紧急号码，即 `110`、`112`、`119`、`120`、`122`、`999`
+///
+/// 紧急号码不属于 [`is_service`] 认为的服务号码
+pub fn is_emergency(number: &str) -> bool {
+    is_within_max_input_len(number) && regex!(EMERGENCY_PATTERN).is_match(number)
+}
+
+/// 给定的号码是否是企业号码，即 `400`、`800` 开头的 10 位号码，
+/// 支持 `400-123-4567` 形式的带 `-` 号码
+pub fn is_business(number: &str) -> bool {
+    is_within_max_input_len(number) && regex!(BUSINESS_PATTERN).is_match(number)
+}
+
+/// 查询全国统一的特服及公共服务短号码的用途，如 `12345` 为 `政务服务便民热线`，未收录的号码返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::short_code_name;
+///
+/// assert_eq!(Some("匪警"), short_code_name("110"));
+/// assert_eq!(Some("消费者投诉举报"), short_code_name("12315"));
+/// assert_eq!(None, short_code_name("10086"));
+/// ```
+pub fn short_code_name(number: &str) -> Option<&'static str> {
+    short_code::SHORT_CODES
+        .binary_search_by(|(code, _)| code.cmp(&number))
+        .ok()
+        .map(|index| short_code::SHORT_CODES[index].1)
+}
+
+/// 是否是收录的非紧急短号码，如 `114`、`121`，这些号码不符合服务号码的格式但同样属于服务号码
+fn is_service_short_code(number: &str) -> bool {
+    short_code_name(number).is_some() && !is_emergency(number)
+}
+
+/// 查询 95、96 开头的服务号码所属的机构，如 `95588` 为 `中国工商银行`，未收录的号码返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::service_provider;
+///
+/// assert_eq!(Some("中国工商银行"), service_provider("95588"));
+/// assert_eq!(None, service_provider("10086"));
+/// ```
+pub fn service_provider(number: &str) -> Option<&'static str> {
+    service_code::SERVICE_CODES
+        .binary_search_by(|(code, _)| code.cmp(&number))
+        .ok()
+        .map(|index| service_code::SERVICE_CODES[index].1)
+}
+
+/// 给定的号码是否是物联网卡号码，即 13 位的 `140`、`141`、`144`、`146`、`148`、`149` 及 `1064` 号段，
+/// 以及 11 位的 `146`、`148`、`172` 号段
+///
+/// 物联网卡仅用于数据通信，不属于 [`is_mobile`] 及 [`is_phone`] 认为的电话号码
+pub fn is_iot(number: &str) -> bool {
+    is_within_max_input_len(number) && regex!(IOT_PATTERN).is_match(number)
+}
+
+/// 给定的号码是否是正常的电话号码，即手机、固定电话、服务号码或长途
+///
+/// 物联网卡（[`is_iot`]）、紧急号码（[`is_emergency`]）及企业号码（[`is_business`]）不在此列，
+/// 需要识别这些号码时请使用 [`classify`]
+pub fn is_phone(number: &str) -> bool {
+    // 按号码长度优先执行最可能匹配的校验，结果与校验顺序无关
+    match number.len() {
+        5 | 8 => is_service(number) || is_mobile(number) || is_telephone(number) || is_idd(number),
+        11 => is_mobile(number) || is_telephone(number) || is_service(number) || is_idd(number),
+        _ => is_telephone(number) || is_mobile(number) || is_idd(number) || is_service(number),
+    }
+}
+
+/// 常用于测试的保留手机号码
+const TEST_MOBILE_NUMBERS: [&str; 3] = ["13800138000", "13800000000", "13000000000"];
+
+/// 给定的号码是否是常用于测试的保留手机号码，如 `13800138000`
+///
+/// 号码会先被转换为中国标准格式后再比较
+pub fn is_test_number(number: &str) -> bool {
+    is_mobile(number) && TEST_MOBILE_NUMBERS.contains(&to_standard_format(number))
+}
+
+/// 给定的手机号码是否看起来可疑，仅用于风控等启发式判断，并非严格校验
+///
+/// 号段（前 3 位）之后的 8 位数字全部相同（如 `13333333333`），
+/// 或严格递增、递减（如 `13012345678`、`13087654321`）时认为可疑
+pub fn looks_suspicious(number: &str) -> bool {
+    if !is_mobile(number) {
+        return false;
+    }
+
+    let digits = safe_slice(to_standard_format(number), 3, 11).as_bytes();
+    let steps = digits
+        .windows(2)
+        .map(|w| i16::from(w[1]) - i16::from(w[0]))
+        .collect::<Vec<_>>();
+
+    steps.iter().all(|step| *step == 0)
+        || steps.iter().all(|step| *step == 1)
+        || steps.iter().all(|step| *step == -1)
+}
+
+/// 给定的字符串是否看起来像是 IMSI（国际移动用户识别码），而非电话号码
+///
+/// IMSI 为 15 位数字，以 3 位移动国家代码（MCC，首位为 2-7，中国为 `460`）开头
+pub fn looks_like_imsi(s: &str) -> bool {
+    is_within_max_input_len(s) && regex!(r"^[2-7]\d{14}$").is_match(s)
+}
+
+/// 给定的字符串是否看起来像是 ICCID（SIM 卡识别码），而非电话号码
+///
+/// ICCID 为 19 或 20 位数字，以电信行业标识 `89` 开头（中国为 `8986`）
+pub fn looks_like_iccid(s: &str) -> bool {
+    is_within_max_input_len(s) && regex!(r"^89\d{17,18}$").is_match(s)
+}
+
+/// 宽松地判断给定的号码是否是正常的电话号码
+///
+/// 与 [`is_phone`] 不同的是，号码会先经过 [`normalize_digits`] 处理，
+/// 并且号码前带有 IP 长途拨号前缀或短信网关前缀时也认为是正常的电话号码
+pub fn is_phone_loose(number: &str) -> bool {
+    if !is_within_max_input_len(number) {
+        return false;
+    }
+
+    let number = normalize_digits(number);
+
+    is_phone(&number)
+        || is_phone(strip_idd_prefix(&number))
+        || is_phone(strip_gateway_prefix(&number))
+}
+
+/// 将全角数字（如 `１３８`）、阿拉伯-印度数字等转换为 ASCII 数字 `0-9`，
+/// 全角的 `＋`、`－` 也会转换为对应的 ASCII 字符
+///
+/// 同时会去除空白字符（如 `+ 86 138 0013 8000` 中的空格）以及零宽字符、BOM、
+/// 双向文本控制符等不可见字符，其它字符维持不变
+pub fn normalize_digits(number: &str) -> String {
+    number
+        .chars()
+        .filter(|c| !c.is_whitespace() && !is_invisible(*c))
+        .map(|c| match c {
+            '０'..='９' => ascii_digit(c, '０'),
+            '\u{0660}'..='\u{0669}' => ascii_digit(c, '\u{0660}'),
+            '\u{06F0}'..='\u{06F9}' => ascii_digit(c, '\u{06F0}'),
+            '＋' => '+',
+            '－' => '-',
+            c => c,
+        })
+        .collect()
+}
+
+/// 规范化号码：在 [`normalize_digits`] 的基础上，去除 `-`、`.`、括号等分隔符，
+/// 如 `138-0013-8000`、`(010) 1234 5678` 分别转换为 `13800138000`、`01012345678`
+///
+/// 完整的固定电话之后的 `-` 视为分机号的分隔符而保留，如 `010-12345678-1234` 转换为 `01012345678-1234`；
+/// 号码无需处理时不会分配内存
+///
+/// ```
+/// use yansongda_utils::phone::{is_phone, normalize};
+///
+/// assert_eq!("01012345678", normalize("(010) 1234-5678"));
+/// assert_eq!("01012345678-1234", normalize("01012345678-1234"));
+/// assert!(is_phone(&normalize("138.0013.8000")));
+/// ```
+pub fn normalize(number: &str) -> Cow<'_, str> {
+    let is_clean = number
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '+' || c == ',');
+
+    if is_clean {
+        return Cow::Borrowed(number);
+    }
+
+    let digits = normalize_digits(number);
+    let strip = |s: &str| s.chars().filter(|c| !is_separator(*c)).collect::<String>();
+
+    if let Some((main, extension)) = digits.rsplit_once('-') {
+        let main = strip(main);
+        let extended = format!("{}-{}", main, extension);
+
+        if is_telephone(&main) && is_telephone(&extended) {
+            return Cow::Owned(extended);
+        }
+    }
+
+    Cow::Owned(strip(&digits))
+}
+
+/// 是否是号码中常见的分隔符
+fn is_separator(c: char) -> bool {
+    matches!(c, '-' | '.' | '(' | ')' | '（' | '）')
+}
+
+/// 是否是零宽字符、BOM 或双向文本控制符
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{061C}'
+            | '\u{FEFF}'
+    )
+}
+
+fn ascii_digit(c: char, zero: char) -> char {
+    char::from(b'0' + (c as u32 - zero as u32) as u8)
+}
+
+/// IP 长途拨号前缀
+const IP_DIALING_PREFIXES: [&str; 5] = ["17951", "12593", "10193", "11808", "17911"];
+
+/// 去除号码前的 IP 长途拨号前缀，如 `17951`、`12593`、`10193`、`11808`、`17911`
+///
+/// 仅当去除前缀后的号码是正常的电话号码时才会去除，否则维持不变
+pub fn strip_idd_prefix(number: &str) -> &str {
+    for prefix in IP_DIALING_PREFIXES {
+        if let Some(rest) = number.strip_prefix(prefix) {
+            if is_phone(rest) {
+                return rest;
+            }
+        }
+    }
+
+    number
+}
+
+/// 短信网关前缀
+const GATEWAY_PREFIXES: [&str; 2] = ["12520", "106"];
+
+/// 去除号码前的短信网关前缀
+///
+/// - `12520`: 中国移动飞信等业务的号码前缀
+/// - `106`: 短信行业网关（营销短信、企业短信等）的号码前缀
+///
+/// 仅当去除前缀后的号码是正常的电话号码时才会去除，否则维持不变
+pub fn strip_gateway_prefix(number: &str) -> &str {
+    for prefix in GATEWAY_PREFIXES {
+        if let Some(rest) = number.strip_prefix(prefix) {
+            if is_phone(rest) {
+                return rest;
+            }
+        }
+    }
+
+    number
+}
+
+/// 给定的号码是否是短信行业网关号码，即 `106` 开头的 8~20 位号码
+///
+/// 13 位的 `1064` 号码为物联网卡号码，不属于短信网关号码
+pub fn is_sms_gateway(number: &str) -> bool {
+    is_within_max_input_len(number) && regex!(r"^106\d{5,17}$").is_match(number) && !is_iot(number)
+}
+
+/// 获取短信行业网关号码的类别，即号码的前 5 位，如 `10690`、`10691`，
+/// 非短信网关号码返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::sms_gateway_class;
+///
+/// assert_eq!(Some("10690"), sms_gateway_class("1069012345678"));
+/// assert_eq!(None, sms_gateway_class("13800138000"));
+/// ```
+pub fn sms_gateway_class(number: &str) -> Option<&str> {
+    if !is_sms_gateway(number) {
+        return None;
+    }
+
+    Some(safe_slice(number, 0, 5))
+}
+
+/// 将号码转换为中国标准格式，即不带 +、+86、86、0 等形式
+/// 如果是国际号码，维持不变
+///
+/// `+` 与国家代码之后的空白字符会被一并去除，如 `+ 86 13800138000` 转换为 `13800138000`；
+/// 由于返回的是原号码的切片，号码中间的空白字符会被保留，需要时请使用 [`standardize_full`]
+///
+/// 该函数是幂等的：对结果再次调用不会发生变化
+pub fn to_standard_format(number: &str) -> &str {
+    let mut standard = number;
+
+    loop {
+        let stripped = strip_standard_prefix(standard);
+
+        if stripped.len() == standard.len() {
+            return standard;
+        }
+
+        standard = stripped;
+    }
+}
+
+/// 剥离一次 +、86、0 等前缀，前缀之后的空白字符（如 `+ 86 138...`）一并剥离
+fn strip_standard_prefix(number: &str) -> &str {
+    let mut offset: usize = 0;
+
+    if safe_slice(number, offset, number.len()).starts_with(char::from_str("+").unwrap()) {
+        offset = skip_whitespace(number, offset + 1);
+    }
+
+    if safe_slice(number, offset, number.len()).starts_with("86") {
+        offset = skip_whitespace(number, offset + 2);
+    }
+
+    let rest = safe_slice(number, offset, number.len());
+    if is_mobile(rest) && rest.starts_with(char::from_str("0").unwrap()) {
+        offset += 1;
+    }
+
+    safe_slice(number, offset, number.len())
+}
+
+/// 跳过 `offset` 处开始的空白字符，返回跳过后的位置
+fn skip_whitespace(number: &str, offset: usize) -> usize {
+    let rest = safe_slice(number, offset, number.len());
+
+    offset + rest.len() - rest.trim_start().len()
+}
+
+/// 去除号码中的空白字符，如 `+ 86 138 0013 8000`，不含空白字符时不会分配内存
+fn strip_whitespace(number: &str) -> Cow<'_, str> {
+    if !number.contains(char::is_whitespace) {
+        return Cow::Borrowed(number);
+    }
+
+    Cow::Owned(number.chars().filter(|c| !c.is_whitespace()).collect())
+}
+
+/// 将号码转换为中国标准格式，去除空白字符，并将分机号的分隔符统一为 `-`
+///
+/// 统一后的号码仍然可以通过 [`is_telephone`] 的校验，如 `01012345678,1234` 转换为 `01012345678-1234`，
+/// `+ 86 138 0013 8000` 转换为 `13800138000`
+pub fn standardize_full(number: &str) -> String {
+    strip_whitespace(to_standard_format(number)).replacen(',', "-", 1)
+}
+
+/// 将号码转换为中国标准格式，并拆分出固定电话的分机号，如 `01012345678-1234` 拆分为 `01012345678` 和 `1234`
+///
+/// 非固定电话或没有分机号时，分机号为 `None`
+pub fn split_extension(number: &str) -> (&str, Option<&str>) {
+    let number = to_standard_format(number);
+
+    if !is_telephone(number) {
+        return (number, None);
+    }
+
+    match number.split_once(['-', ',']) {
+        Some((main, extension)) => (main, Some(extension)),
+        None => (number, None),
+    }
+}
+
+/// 将号码与分机号以 `-` 连接，是 [`split_extension`] 的逆操作，分机号为 `None` 时返回号码本身
+pub fn join_extension(main: &str, extension: Option<&str>) -> String {
+    match extension {
+        Some(extension) => format!("{}-{}", main, extension),
+        None => main.to_owned(),
+    }
+}
+
+/// 将两个连在一起的号码拆分开，如 `1380013800001012345678` 拆分为 `13800138000` 和 `01012345678`
+///
+/// 两部分都必须是电话号码。存在多种拆分方式时，优先选择第一部分是手机或固定电话的，
+/// 其次选择第二部分是手机或固定电话的，同等条件下拆分位置靠前者优先；无法拆分时返回 `None`
+pub fn split_concatenated(s: &str) -> Option<(&str, &str)> {
+    let is_subscriber = |number: &str| is_mobile(number) || is_telephone(number);
+
+    s.char_indices()
+        .skip(1)
+        .map(|(i, _)| s.split_at(i))
+        .filter(|(first, second)| is_phone(first) && is_phone(second))
+        .min_by_key(|(first, second)| {
+            Reverse(u8::from(is_subscriber(first)) * 2 + u8::from(is_subscriber(second)))
+        })
+}
+
+/// 获取号码用于数据库索引的键，即中国标准格式号码（不含分机号）的后 8 位
+///
+/// 不足 8 位的号码（如服务号码 `10086`）返回完整号码，不是电话号码时返回 `None`
+pub fn index_key(number: &str) -> Option<String> {
+    if !is_phone(number) {
+        return None;
+    }
+
+    let number = to_standard_format(number);
+    let main = number.split(['-', ',']).next().unwrap_or(number);
+
+    Some(safe_slice(main, main.len().saturating_sub(8), main.len()).to_owned())
+}
+
+/// 获取号码的号段，以便区分运营商，获取号段详细归属地信息
+///
+/// 号码会先被转换为中国标准格式，因此 `+86`、`0` 等前缀不会影响结果
+pub fn get_segment(number: &str) -> (PhoneType, &str) {
+    let number = to_standard_format(number);
+    let phone_type = detect_type(number).unwrap_or(PhoneType::Tel);
+    let segment = segment_range(&phone_type, number);
+
+    (phone_type, safe_slice(number, segment.start, segment.end))
+}
+
+/// 获取号码的号段，与 [`get_segment`] 不同的是，不是电话号码时返回错误
+pub fn try_get_segment(number: &str) -> Result<(PhoneType, &str), PhoneError> {
+    let (phone_type, standard) = try_detect_type(number)?;
+    let segment = segment_range(&phone_type, standard);
+
+    Ok((phone_type, safe_slice(standard, segment.start, segment.end)))
+}
+
+/// 校验号码并返回号码类型，校验失败时返回具体的原因
+///
+/// 固定电话还会校验区号是否真实存在
+///
+/// ```
+/// use yansongda_utils::phone::{validate, PhoneType, ValidationError};
+///
+/// assert_eq!(Ok(PhoneType::Mobile), validate("+8613800138000"));
+/// assert_eq!(Err(ValidationError::IllegalCharacter('a')), validate("1380013800a"));
+/// assert_eq!(Err(ValidationError::BadLength(10)), validate("1380013800"));
+/// ```
+pub fn validate(number: &str) -> Result<PhoneType, ValidationError> {
+    if number.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+
+    if !is_within_max_input_len(number) {
+        return Err(ValidationError::TooLong(number.len()));
+    }
+
+    let standard = to_standard_format(number);
+
+    if let Some(phone_type) = detect_type(standard) {
+        if phone_type == PhoneType::Tel && !is_telephone_strict(standard) {
+            let area_code = safe_slice(standard, 0, area_code_len(standard));
+
+            return Err(ValidationError::UnknownAreaCode(area_code.to_owned()));
+        }
+
+        return Ok(phone_type);
+    }
+
+    if let Some(c) = standard
+        .chars()
+        .find(|c| !c.is_ascii_digit() && !matches!(c, '+' | '-' | ','))
+    {
+        return Err(ValidationError::IllegalCharacter(c));
+    }
+
+    if regex!(r"^1[0-9]{10}$").is_match(standard) {
+        return Err(ValidationError::UnallocatedPrefix(
+            safe_slice(standard, 0, 3).to_owned(),
+        ));
+    }
+
+    Err(ValidationError::BadLength(standard.len()))
+}
+
+/// 转换为中国标准格式并判断号码类型，不是电话号码时返回错误
+fn try_detect_type(number: &str) -> Result<(PhoneType, &str), PhoneError> {
+    if number.is_empty() {
+        return Err(PhoneError::Empty);
+    }
+
+    if !is_within_max_input_len(number) {
+        return Err(PhoneError::TooLong(number.len()));
+    }
+
+    let standard = to_standard_format(number);
+
+    detect_type(standard)
+        .map(|phone_type| (phone_type, standard))
+        .ok_or_else(|| PhoneError::Invalid(number.to_owned()))
+}
+
+/// 标准格式号码中号段所在的范围
+fn segment_range(phone_type: &PhoneType, number: &str) -> Range<usize> {
+    match phone_type {
+        PhoneType::Mobile => 0..7,
+        PhoneType::Idd => match country_code_len(number) {
+            Some(len) => 2..2 + len,
+            None => 2..6,
+        },
+        PhoneType::Service | PhoneType::Emergency => 0..number.len(),
+        PhoneType::Business => 0..3,
+        PhoneType::Iot if number.starts_with("1064") => 0..5,
+        PhoneType::Iot => 0..3,
+        _ => 0..area_code_len(number),
+    }
+}
+
+/// 国际长途号码中国际区号的长度，按最长匹配查找，未知区号返回 `None`
+fn country_code_len(number: &str) -> Option<usize> {
+    let rest = number.strip_prefix("00")?;
+
+    (1..=3)
+        .rev()
+        .find(|&len| rest.len() > len && calling_code_country(safe_slice(rest, 0, len)).is_some())
+}
+
+/// 查询国际电话区号对应的国家或地区，如 `852` 为中国香港，未分配的区号返回 `None`
+pub fn calling_code_country(calling_code: &str) -> Option<&'static Country> {
+    country_code::COUNTRY_CODES
+        .binary_search_by_key(&calling_code, |country| country.calling_code)
+        .ok()
+        .map(|index| &country_code::COUNTRY_CODES[index])
+}
+
+/// 查询国际长途号码所属的国家或地区，非国际长途号码或国际区号未分配时返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::idd_country;
+///
+/// let country = idd_country("0085212345678").unwrap();
+///
+/// assert_eq!(Some("HK"), country.iso);
+/// assert_eq!("中国香港", country.name_zh);
+/// assert_eq!("Hong Kong", country.name_en);
+/// ```
+pub fn idd_country(number: &str) -> Option<&'static Country> {
+    split_country_code(number).and_then(|(calling_code, _)| calling_code_country(calling_code))
+}
+
+/// 拆分国际长途号码的国际区号与其后的号码，如 `0085212345678` 拆分为 `852` 和 `12345678`
+///
+/// 非国际长途号码或国际区号未分配时返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::split_country_code;
+///
+/// assert_eq!(Some(("852", "12345678")), split_country_code("0085212345678"));
+/// assert_eq!(Some(("1", "2025550123")), split_country_code("0012025550123"));
+/// assert_eq!(None, split_country_code("13800138000"));
+/// ```
+pub fn split_country_code(number: &str) -> Option<(&str, &str)> {
+    let number = to_standard_format(number);
+
+    if !is_idd(number) {
+        return None;
+    }
+
+    let len = country_code_len(number)?;
+
+    Some((
+        safe_slice(number, 2, 2 + len),
+        safe_slice(number, 2 + len, number.len()),
+    ))
+}
+
+/// 固定电话区号的长度，按区号表最长匹配，如 `010`、`02x` 为 3 位；区号表中不存在时默认为 4 位
+fn area_code_len(number: &str) -> usize {
+    find_area_code_len(number).unwrap_or(4)
+}
+
+/// 按区号表查找固定电话区号的长度，区号不存在时返回 `None`
+fn find_area_code_len(number: &str) -> Option<usize> {
+    [4, 3]
+        .into_iter()
+        .find(|&len| number.len() > len && area_code_city(safe_slice(number, 0, len)).is_some())
+}
+
+/// 查询固定电话区号对应的城市，如 `0755` 为广东深圳，区号不存在时返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::area_code_city;
+///
+/// let city = area_code_city("0755").unwrap();
+///
+/// assert_eq!("广东", city.province);
+/// assert_eq!("深圳", city.name);
+/// assert_eq!("440300", city.admin_code);
+/// ```
+pub fn area_code_city(area_code: &str) -> Option<&'static City> {
+    area_code::AREA_CODES
+        .binary_search_by_key(&area_code, |city| city.area_code)
+        .ok()
+        .map(|index| &area_code::AREA_CODES[index])
+}
+
+/// 拆分固定电话的区号与本地号码，如 `075512345678-1234` 拆分为 `0755` 和 `12345678`，分机号不包含在内
+///
+/// 非固定电话或区号不存在时返回 `None`
+///
+/// ```
+/// use yansongda_utils::phone::split_area_code;
+///
+/// assert_eq!(Some(("010", "12345678")), split_area_code("+8601012345678"));
+/// assert_eq!(Some(("0755", "12345678")), split_area_code("075512345678-1234"));
+/// assert_eq!(None, split_area_code("13800138000"));
+/// ```
+pub fn split_area_code(number: &str) -> Option<(&str, &str)> {
+    let (main, _) = split_extension(number);
+
+    if !is_telephone(main) || is_idd(main) {
+        return None;
+    }
+
+    let len = find_area_code_len(main)?;
+
+    Some((safe_slice(main, 0, len), safe_slice(main, len, main.len())))
+}
+
+/// 区号长度与本地号码允许的长度
+const TELEPHONE_LENGTH_RULES: [(usize, &[usize]); 2] = [(3, &[8]), (4, &[7, 8])];
+
+/// 给定的固定电话号码，其本地号码长度与区号长度是否匹配
+///
+/// 3 位区号（如北京 `010`）的本地号码为 8 位，4 位区号的本地号码为 7 或 8 位；
+/// 分机号不参与校验
+pub fn is_telephone_length_consistent(number: &str) -> bool {
+    if !is_telephone(number) {
+        return false;
+    }
+
+    let number = to_standard_format(number);
+    let main = number.split(['-', ',']).next().unwrap_or(number);
+    let area_len = area_code_len(main);
+
+    TELEPHONE_LENGTH_RULES
+        .iter()
+        .find(|(len, _)| *len == area_len)
+        .is_some_and(|(_, local_lens)| local_lens.contains(&(main.len() - area_len)))
+}
+
+/// 号码展示格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayStyle {
+    /// 国际格式，如 `+86 138 0013 8000`、`+86 10 1234 5678`
+    International,
+    /// 国内格式，如 `138-0013-8000`、`010-12345678`
+    National,
+    /// 紧凑格式，如 `13800138000`、`01012345678`
+    Compact,
+}
+
+/// 按指定格式展示号码，仅支持手机及固定电话，国际长途等其它号码返回 `None`
+///
+/// 固定电话的分机号在国际格式中以 ` ext. 1234` 的形式展示，其它格式中以 `-1234` 的形式展示
+pub fn format_display(number: &str, style: DisplayStyle) -> Option<String> {
+    let number = to_standard_format(number);
+
+    if is_mobile(number) {
+        let (segment, middle, last) = (
+            safe_slice(number, 0, 3),
+            safe_slice(number, 3, 7),
+            safe_slice(number, 7, 11),
+        );
+
+        return Some(match style {
+            DisplayStyle::International => format!("+86 {} {} {}", segment, middle, last),
+            DisplayStyle::National => format!("{}-{}-{}", segment, middle, last),
+            DisplayStyle::Compact => number.to_owned(),
+        });
+    }
+
+    if !is_telephone(number) || is_idd(number) {
+        return None;
+    }
+
+    let mut parts = number.splitn(2, ['-', ',']);
+    let main = parts.next().unwrap_or(number);
+    let extension = parts.next();
+    let area_len = area_code_len(main);
+    let (area_code, local) = (
+        safe_slice(main, 0, area_len),
+        safe_slice(main, area_len, main.len()),
+    );
+
+    let mut display = match style {
+        DisplayStyle::International => {
+            let split = local.len() - 4;
+
+            format!(
+                "+86 {} {} {}",
+                &area_code[1..],
+                safe_slice(local, 0, split),
+                safe_slice(local, split, local.len())
+            )
+        }
+        DisplayStyle::National => format!("{}-{}", area_code, local),
+        DisplayStyle::Compact => main.to_owned(),
+    };
+
+    if let Some(extension) = extension {
+        match style {
+            DisplayStyle::International => display.push_str(" ext. "),
+            _ => display.push('-'),
+        }
+        display.push_str(extension);
+    }
+
+    Some(display)
+}
+
+/// 流式校验 CSV 中指定列（从 0 开始）的电话号码，依次返回 `(行号, 是否合法)`
+///
+/// 行号从 0 开始且包含表头行；该行缺少指定列或无法解析时视为不合法
+#[cfg(feature = "csv")]
+pub fn validate_csv_column<R: std::io::Read>(
+    reader: R,
+    column: usize,
+) -> impl Iterator<Item = (usize, bool)> {
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(reader)
+        .into_records()
+        .enumerate()
+        .map(move |(row, record)| {
+            let valid = record
+                .ok()
+                .and_then(|record| record.get(column).map(is_phone))
+                .unwrap_or(false);
+
+            (row, valid)
+        })
+}
+
+/// 按文本中出现的顺序，依次返回文本中的电话号码及其类型
+///
+/// 区号与本地号码之间以 `-` 分隔的固定电话，如 `010-12345678`，作为整体返回
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone::{self, PhoneType};
+///
+/// let mut spans = phone::scan("手机 13800138000，客服 10086");
+///
+/// assert_eq!(Some((PhoneType::Mobile, "13800138000")), spans.next());
+/// assert_eq!(Some((PhoneType::Service, "10086")), spans.next());
+/// assert_eq!(None, spans.next());
+/// ```
+pub fn scan(text: &str) -> impl Iterator<Item = (PhoneType, &str)> {
+    let number_re = regex!(r"\+?\d+");
+    let area_code_re = regex!(r"^0[0-9]{2,3}$");
+    let local_re = regex!(r"^-[0-9]{7,8}\b");
+    let extension_re = regex!(r"^[-,]\d+");
+    let mut offset: usize = 0;
+
+    std::iter::from_fn(move || {
+        while let Some(m) = number_re.find_at(text, offset) {
+            let mut end = m.end();
+            let mut dashed = false;
+
+            // 区号与本地号码之间的 `-`，如 `010-12345678`
+            if area_code_re.is_match(m.as_str()) {
+                if let Some(local) = local_re.find(&text[end..]) {
+                    end += local.end();
+                    dashed = true;
+                }
+            }
+
+            let undash = |s| match dashed {
+                true => Cow::Owned(str::replacen(s, '-', "", 1)),
+                false => Cow::Borrowed(s),
+            };
+
+            if let Some(ext) = extension_re.find(&text[end..]) {
+                if is_telephone(&undash(&text[m.start()..end + ext.end()])) {
+                    end += ext.end();
+                }
+            }
+
+            offset = end;
+
+            let candidate = &text[m.start()..end];
+            if let Some(phone_type) = detect_type(&undash(candidate)) {
+                return Some((phone_type, candidate));
+            }
+        }
+
+        None
+    })
+}
+
+/// 按 手机、物联网、长途、紧急号码、企业号码、服务号码、固定电话 的优先级判断号码类型，均不符合时返回 [`PhoneType::Others`]
+///
+/// ```
+/// use yansongda_utils::phone::{classify, PhoneType};
+///
+/// assert_eq!(PhoneType::Mobile, classify("13800138000"));
+/// assert_eq!(PhoneType::Idd, classify("008512345678"));
+/// assert_eq!(PhoneType::Others, classify("abc"));
+/// ```
+pub fn classify(number: &str) -> PhoneType {
+    detect_type(number).unwrap_or(PhoneType::Others)
+}
+
+/// 返回给定号码可能属于的所有类型，按与 [`classify`] 相同的优先级排列
+///
+/// 部分号码可能同时符合多个类型，如 `008512345678` 既是国际长途也符合固定电话的格式
+pub fn classify_all(number: &str) -> Vec<PhoneType> {
+    CLASSIFY_ORDER
+        .into_iter()
+        .filter(|phone_type| is_type(number, phone_type))
+        .collect()
+}
+
+/// 号码匹配结果的说明，用于排查号码为何被判断为某种类型
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// 判断出的号码类型
+    pub phone_type: PhoneType,
+    /// 匹配的规则名称，如 `mobile`、`iot`、`emergency`、`business`、`telephone`、`service`、`short_code`、`idd`
+    pub pattern: &'static str,
+    /// 匹配的字节范围
+    pub range: Range<usize>,
+}
+
+/// 说明给定的号码按与 [`classify`] 相同的优先级匹配了哪条规则，
+/// 不是电话号码时返回 `None`
+pub fn explain(number: &str) -> Option<Explanation> {
+    let phone_type = detect_type(number)?;
+    let (pattern, re) = match phone_type {
+        PhoneType::Mobile => ("mobile", regex!(MOBILE_PATTERN)),
+        PhoneType::Idd => ("idd", regex!(IDD_PATTERN)),
+        PhoneType::Iot => ("iot", regex!(IOT_PATTERN)),
+        PhoneType::Emergency => ("emergency", regex!(EMERGENCY_PATTERN)),
+        PhoneType::Business => ("business", regex!(BUSINESS_PATTERN)),
+        PhoneType::Service => ("service", regex!(SERVICE_PATTERN)),
+        _ => ("telephone", regex!(TELEPHONE_PATTERN)),
+    };
+
+    if let Some(m) = re.find(number) {
+        return Some(Explanation {
+            phone_type,
+            pattern,
+            range: m.range(),
+        });
+    }
+
+    is_service_short_code(number).then_some(Explanation {
+        phone_type,
+        pattern: "short_code",
+        range: 0..number.len(),
+    })
+}
+
+fn detect_type(number: &str) -> Option<PhoneType> {
+    CLASSIFY_ORDER
+        .into_iter()
+        .find(|phone_type| is_type(number, phone_type))
+}
+
+fn is_type(number: &str, phone_type: &PhoneType) -> bool {
+    match phone_type {
+        PhoneType::Mobile => is_mobile(number),
+        PhoneType::Iot => is_iot(number),
+        PhoneType::Idd => is_idd(number),
+        PhoneType::Emergency => is_emergency(number),
+        PhoneType::Business => is_business(number),
+        PhoneType::Service => is_service(number),
+        PhoneType::Tel => is_telephone(number),
+        _ => false,
+    }
+}
+
+/// 获取手机号码的 3 位号段，如 `138`，非手机号码返回 `None`
+pub fn mobile_segment3(number: &str) -> Option<&str> {
+    let number = to_standard_format(number);
+
+    if !is_mobile(number) {
+        return None;
+    }
+
+    Some(safe_slice(number, 0, 3))
+}
+
+/// 根据号段判断手机号码所属的运营商，非手机号码或未知号段返回 [`MobileVendor::Others`]
+///
+/// 携号转网后的号码无法通过号段判断，返回的是号段最初分配的运营商；
+/// 如需使用其它数据源，请使用 [`lookup_with`]
+///
+/// ```
+/// use yansongda_utils::phone::{get_vendor, MobileVendor};
+///
+/// assert_eq!(MobileVendor::Mobile, get_vendor("13800138000"));
+/// assert_eq!(MobileVendor::Cbn, get_vendor("+8619200138000"));
+/// assert_eq!(MobileVendor::Others, get_vendor("10086"));
+/// ```
+pub fn get_vendor(number: &str) -> MobileVendor {
+    let number = to_standard_format(number);
+
+    if !is_mobile(number) {
+        return MobileVendor::Others;
+    }
+
+    find_mobile_segment(number).map_or(MobileVendor::Others, |(_, vendor)| vendor.clone())
+}
+
+/// 号段对应的运营商是否与给定的运营商不一致，可用于粗略标记可能已携号转网的号码
+///
+/// ```
+/// use yansongda_utils::phone::{segment_vendor_mismatch, MobileVendor};
+///
+/// assert!(segment_vendor_mismatch("13800138000", MobileVendor::Telecom));
+/// assert!(!segment_vendor_mismatch("13800138000", MobileVendor::Mobile));
+/// ```
+pub fn segment_vendor_mismatch(number: &str, claimed: MobileVendor) -> bool {
+    get_vendor(number) != claimed
+}
+
+/// 给定的号码是否是虚拟运营商（MVNO）的手机号码，如 `170`、`171`、`162`、`165`、`167` 号段
+///
+/// 虚拟运营商转售的基础运营商可通过 [`get_vendor`] 获取
+///
+/// ```
+/// use yansongda_utils::phone::{get_vendor, is_virtual, MobileVendor};
+///
+/// assert!(is_virtual("17031234567"));
+/// assert_eq!(MobileVendor::Mobile, get_vendor("17031234567"));
+/// assert!(!is_virtual("13800138000"));
+/// ```
+pub fn is_virtual(number: &str) -> bool {
+    let number = to_standard_format(number);
+
+    is_mobile(number)
+        && mobile_segment::VIRTUAL_SEGMENTS
+            .binary_search(&safe_slice(number, 0, 3))
+            .is_ok()
+}
+
+/// 给定的号码是否是卫星电话号码，如天通卫星电话的 `1749` 号段
+///
+/// 卫星电话同时也符合 [`is_mobile`]，但通常无法正常接收短信
+pub fn is_satellite(number: &str) -> bool {
+    let number = to_standard_format(number);
+
+    is_mobile(number)
+        && mobile_segment::SATELLITE_SEGMENTS
+            .binary_search(&safe_slice(number, 0, 4))
+            .is_ok()
+}
+
+/// 号码的汇总信息
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PhoneInfo {
+    /// 号码类型
+    pub phone_type: PhoneType,
+    /// 运营商，非手机号码为 [`MobileVendor::Others`]
+    pub vendor: MobileVendor,
+    /// 号段，同 [`PhoneNumber::segment`]
+    pub segment: String,
+    /// 中国标准格式的号码，不含分机号
+    pub standard: String,
+}
+
+/// 一次性获取号码的类型、运营商、号段及标准格式，不是电话号码时返回错误
+///
+/// ```
+/// use yansongda_utils::phone::{info, MobileVendor, PhoneType};
+///
+/// let info = info("+8613800138000").unwrap();
+///
+/// assert_eq!(PhoneType::Mobile, info.phone_type);
+/// assert_eq!(MobileVendor::Mobile, info.vendor);
+/// assert_eq!("1380013", info.segment);
+/// assert_eq!("13800138000", info.standard);
+/// ```
+pub fn info(number: &str) -> Result<PhoneInfo, PhoneError> {
+    let number = PhoneNumber::parse(number)?;
+
+    Ok(PhoneInfo {
+        phone_type: number.phone_type().clone(),
+        vendor: get_vendor(number.number()),
+        segment: number.segment().to_owned(),
+        standard: number.number().to_owned(),
+    })
+}
+
+/// 安全地截取字符串 `s[start..end]`
+///
+/// `start`、`end` 超出字符串长度时会被截断到字符串长度，
+/// 不在字符边界上时会向前调整到最近的字符边界，因此永远不会 panic
+pub(crate) fn safe_slice(s: &str, start: usize, end: usize) -> &str {
+    let end = floor_char_boundary(s, end);
+    let start = floor_char_boundary(s, start.min(end));
+
+    &s[start..end]
+}
+
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use serde::de::DeserializeOwned;
+
+    use super::*;
+
+    pub(super) fn assert_json_roundtrip<T>(v: T)
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let json = serde_json::to_string(&v).unwrap();
+
+        assert_eq!(v, serde_json::from_str::<T>(&json).unwrap(), "{}", json);
+    }
+
+    #[test]
+    fn test_phone_type_string() {
+        assert_eq!("MOBILE", PhoneType::Mobile.to_string());
+        assert_eq!("TEL", PhoneType::Tel.to_string());
+        assert_eq!("SERVICE", PhoneType::Service.to_string());
+        assert_eq!("IDD", PhoneType::Idd.to_string());
+
+        assert_eq!(PhoneType::Mobile, "MOBILE".to_string().into());
+        assert_eq!(PhoneType::Tel, "TeL".to_string().into());
+        assert_eq!(PhoneType::Service, "SERViCE".to_string().into());
+        assert_eq!(PhoneType::Idd, "IDD".to_string().into());
+    }
+
+    #[test]
+    fn test_phone_type_from_str_slice() {
+        for v in ["MOBILE", "TeL", "SERViCE", "idd", "unknown", ""] {
+            assert_eq!(PhoneType::from(v), PhoneType::from(v.to_string()));
+        }
+
+        assert_eq!(PhoneType::Mobile, "mobile".into());
+        assert_eq!(PhoneType::Others, "unknown".into());
+    }
+
+    #[test]
+    fn test_phone_type_as_str() {
+        let mobile: &'static str = PhoneType::Mobile.as_str();
+
+        assert_eq!("MOBILE", mobile);
+        assert_eq!(PhoneType::Tel.to_string(), PhoneType::Tel.as_str());
+        assert_eq!(PhoneType::Others.to_string(), PhoneType::Others.as_str());
+    }
+
+    #[test]
+    fn test_phone_type_lowercase() {
+        assert_eq!("tel", PhoneType::Tel.as_lowercase());
+        assert_eq!("mobile", PhoneType::Mobile.as_lowercase());
+        assert_eq!("idd", PhoneType::Idd.as_lowercase());
+        assert_eq!("service", PhoneType::Service.as_lowercase());
+        assert_eq!("iot", PhoneType::Iot.as_lowercase());
+        assert_eq!("others", PhoneType::Others.as_lowercase());
+
+        for phone_type in [
+            PhoneType::Tel,
+            PhoneType::Mobile,
+            PhoneType::Idd,
+            PhoneType::Service,
+            PhoneType::Iot,
+            PhoneType::Emergency,
+            PhoneType::Business,
+            PhoneType::Others,
+        ] {
+            assert_eq!(phone_type, phone_type.as_lowercase().into());
+        }
+    }
+
+    #[test]
+    fn test_phone_type_parse() {
+        assert_eq!(Ok(PhoneType::Mobile), "mobile".parse::<PhoneType>());
+        assert_eq!(Ok(PhoneType::Tel), "TEL".parse::<PhoneType>());
+        assert_eq!(Ok(PhoneType::Others), "unknown".parse::<PhoneType>());
+    }
+
+    #[test]
+    fn test_phone_type_dialable() {
+        assert!(PhoneType::Mobile.is_dialable());
+        assert!(PhoneType::Tel.is_dialable());
+        assert!(PhoneType::Service.is_dialable());
+        assert!(PhoneType::Idd.is_dialable());
+        assert!(PhoneType::Emergency.is_dialable());
+        assert!(!PhoneType::Emergency.is_internationally_dialable());
+        assert!(PhoneType::Business.is_dialable());
+        assert!(!PhoneType::Business.is_internationally_dialable());
+        assert!(!PhoneType::Iot.is_dialable());
+        assert!(!PhoneType::Others.is_dialable());
+
+        assert!(PhoneType::Mobile.is_internationally_dialable());
+        assert!(PhoneType::Tel.is_internationally_dialable());
+        assert!(!PhoneType::Service.is_internationally_dialable());
+        assert!(PhoneType::Idd.is_internationally_dialable());
+        assert!(!PhoneType::Others.is_internationally_dialable());
+    }
+
+    #[test]
+    fn test_phone_type_json() {
+        assert_eq!(
+            PhoneType::Mobile,
+            serde_json::from_str("\"MOBILE\"").unwrap()
+        );
+        assert_eq!(PhoneType::Tel, serde_json::from_str("\"TeL\"").unwrap());
+        assert_eq!(
+            PhoneType::Service,
+            serde_json::from_str("\"SERViCE\"").unwrap()
+        );
+        assert_eq!(PhoneType::Idd, serde_json::from_str("\"IDD\"").unwrap());
+
+        for phone_type in [
+            PhoneType::Tel,
+            PhoneType::Mobile,
+            PhoneType::Idd,
+            PhoneType::Service,
+            PhoneType::Others,
+        ] {
+            assert_json_roundtrip(phone_type);
+        }
+    }
+
+    #[test]
+    fn test_mobile_vendor_string() {
+        assert_eq!("10086 移动", MobileVendor::Mobile.to_string());
+        assert_eq!("10010 联通", MobileVendor::Unicom.to_string());
+        assert_eq!("10000 电信", MobileVendor::Telecom.to_string());
+        assert_eq!("10099 广电", MobileVendor::Cbn.to_string());
+
+        assert_eq!(MobileVendor::Mobile, "10086 移动".to_string().into());
+        assert_eq!(MobileVendor::Mobile, "mObIle".to_string().into());
+        assert_eq!(MobileVendor::Unicom, "10010 联通".to_string().into());
+        assert_eq!(MobileVendor::Unicom, "10010".to_string().into());
+        assert_eq!(MobileVendor::Telecom, "10000 电信".to_string().into());
+        assert_eq!(MobileVendor::Telecom, "telecom".to_string().into());
+        assert_eq!(MobileVendor::Cbn, "10099 广电".to_string().into());
+        assert_eq!(MobileVendor::Cbn, "cbn".to_string().into());
+    }
+
+    #[test]
+    fn test_mobile_vendor_from_str_slice() {
+        for v in [
+            "10086 移动",
+            "mObIle",
+            "10010",
+            "UNICOM",
+            "10000 电信",
+            "telecom",
+            "10099",
+            "Cbn",
+            "unknown 未知",
+        ] {
+            assert_eq!(MobileVendor::from(v), MobileVendor::from(v.to_string()));
+        }
+
+        assert_eq!(MobileVendor::Unicom, "unicom".into());
+        assert_eq!(MobileVendor::Others, "unknown".into());
+    }
+
+    #[test]
+    fn test_mobile_vendor_code_name() {
+        assert_eq!("10086", MobileVendor::Mobile.code());
+        assert_eq!("移动", MobileVendor::Mobile.name());
+        assert_eq!("10010", MobileVendor::Unicom.code());
+        assert_eq!("联通", MobileVendor::Unicom.name());
+        assert_eq!("10000", MobileVendor::Telecom.code());
+        assert_eq!("电信", MobileVendor::Telecom.name());
+        assert_eq!("10099", MobileVendor::Cbn.code());
+        assert_eq!("广电", MobileVendor::Cbn.name());
+        assert_eq!("unknown", MobileVendor::Others.code());
+        assert_eq!("未知", MobileVendor::Others.name());
+    }
+
+    #[test]
+    fn test_mobile_vendor_parse() {
+        assert_eq!(Ok(MobileVendor::Mobile), "10086".parse::<MobileVendor>());
+        assert_eq!(Ok(MobileVendor::Unicom), "Unicom".parse::<MobileVendor>());
+        assert_eq!(Ok(MobileVendor::Others), "unknown".parse::<MobileVendor>());
+    }
+
+    #[test]
+    fn test_mobile_vendor_ord() {
+        let mut vendors = vec![
+            MobileVendor::Others,
+            MobileVendor::Telecom,
+            MobileVendor::Cbn,
+            MobileVendor::Mobile,
+            MobileVendor::Unicom,
+        ];
+        vendors.sort();
+
+        assert_eq!(
+            vec![
+                MobileVendor::Mobile,
+                MobileVendor::Unicom,
+                MobileVendor::Telecom,
+                MobileVendor::Cbn,
+                MobileVendor::Others,
+            ],
+            vendors
+        );
+    }
+
+    #[test]
+    fn test_mobile_vendor_json() {
+        assert_eq!(
+            MobileVendor::Mobile,
+            serde_json::from_str("\"10086 移动\"").unwrap()
+        );
+        assert_eq!(
+            MobileVendor::Mobile,
+            serde_json::from_str("\"mObIle\"").unwrap()
+        );
+        assert_eq!(
+            MobileVendor::Unicom,
+            serde_json::from_str("\"10010 联通\"").unwrap()
+        );
+        assert_eq!(
+            MobileVendor::Unicom,
+            serde_json::from_str("\"10010\"").unwrap()
+        );
+        assert_eq!(
+            MobileVendor::Telecom,
+            serde_json::from_str("\"10000 电信\"").unwrap()
+        );
+        assert_eq!(
+            MobileVendor::Telecom,
+            serde_json::from_str("\"telecom\"").unwrap()
+        );
+        assert_eq!(
+            MobileVendor::Cbn,
+            serde_json::from_str("\"10099 广电\"").unwrap()
+        );
+        assert_eq!(MobileVendor::Cbn, serde_json::from_str("\"cbn\"").unwrap());
+
+        for vendor in [
+            MobileVendor::Mobile,
+            MobileVendor::Unicom,
+            MobileVendor::Telecom,
+            MobileVendor::Cbn,
+            MobileVendor::Others,
+        ] {
+            assert_json_roundtrip(vendor);
+        }
+    }
+
+    #[test]
+    fn test_mobile_vendor_object_json() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Wrapper {
+            #[serde(with = "vendor_serde::object")]
+            vendor: MobileVendor,
+        }
+
+        let json = serde_json::to_string(&Wrapper {
+            vendor: MobileVendor::Mobile,
+        })
+        .unwrap();
+
+        assert_eq!(r#"{"vendor":{"code":"10086","name":"移动"}}"#, json);
+
+        for vendor in [
+            MobileVendor::Mobile,
+            MobileVendor::Unicom,
+            MobileVendor::Telecom,
+            MobileVendor::Cbn,
+            MobileVendor::Others,
+        ] {
+            let wrapper = Wrapper { vendor };
+            let json = serde_json::to_string(&wrapper).unwrap();
+
+            assert_eq!(wrapper, serde_json::from_str(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_flexible_serde_json() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Wrapper {
+            #[serde(with = "flexible_serde")]
+            phone: String,
+        }
+
+        let from_str: Wrapper = serde_json::from_str(r#"{"phone":"13800138000"}"#).unwrap();
+        let from_number: Wrapper = serde_json::from_str(r#"{"phone":13800138000}"#).unwrap();
+
+        assert_eq!("13800138000", from_str.phone);
+        assert_eq!(from_str, from_number);
+        assert_eq!(
+            r#"{"phone":"13800138000"}"#,
+            serde_json::to_string(&from_number).unwrap()
+        );
+
+        assert!(serde_json::from_str::<Wrapper>(r#"{"phone":"abc"}"#).is_err());
+        assert!(serde_json::from_str::<Wrapper>(r#"{"phone":123}"#).is_err());
+        assert!(serde_json::from_str::<Wrapper>(r#"{"phone":-13800138000}"#).is_err());
+    }
+
+    #[test]
+    fn test_mobile_number_json() {
+        let number: MobileNumber = serde_json::from_str("\"+8613800138000\"").unwrap();
+
+        assert_eq!("+8613800138000", number.as_str());
+        assert_eq!("13800138000", number.standardized());
+        assert_eq!(MobileVendor::Mobile, number.vendor());
+        assert_eq!(
+            "\"+8613800138000\"",
+            serde_json::to_string(&number).unwrap()
+        );
+
+        assert!(serde_json::from_str::<MobileNumber>("\"01012345678\"").is_err());
+        assert!(serde_json::from_str::<MobileNumber>("13800138000").is_err());
+
+        assert_json_roundtrip(number);
+    }
+
+    #[test]
+    fn test_mobile_number_new() {
+        let number = MobileNumber::new("+8613800138000").unwrap();
+
+        assert_eq!("13800138000", number.standardized());
+        assert_eq!(Ok(number), "+8613800138000".parse::<MobileNumber>());
+        assert_eq!(
+            Err(PhoneError::Invalid(String::from("01012345678"))),
+            MobileNumber::new("01012345678")
+        );
+        assert!("abc".parse::<MobileNumber>().is_err());
+    }
+
+    #[test]
+    fn test_block_list() {
+        let list = BlockList::from_numbers(["13800138000", "+8601012345678"]);
+
+        assert!(list.contains("13800138000"));
+        assert!(list.contains("+8613800138000"));
+        assert!(list.contains("013800138000"));
+        assert!(list.contains("01012345678"));
+        assert!(!list.contains("13900139000"));
+
+        let list = BlockList::from_numbers(vec![String::from("+8613800138000")]);
+
+        assert!(list.contains("13800138000"));
+    }
+
+    #[test]
+    fn test_phone_validator() {
+        assert!(PhoneValidator::try_new().is_ok());
+
+        let validator = PhoneValidator::new();
+
+        for number in [
+            "13800138000",
+            "+8613800138000",
+            "01012345678-1234",
+            "075512345678",
+            "10086",
+            "95588",
+            "008512345678",
+            "12",
+            "abc",
+        ] {
+            assert_eq!(is_mobile(number), validator.is_mobile(number));
+            assert_eq!(is_telephone(number), validator.is_telephone(number));
+            assert_eq!(is_service(number), validator.is_service(number));
+            assert_eq!(is_idd(number), validator.is_idd(number));
+            assert_eq!(is_phone(number), validator.is_phone(number));
+            assert_eq!(classify_all(number), validator.classify_all(number));
+        }
+    }
+
+    #[test]
+    fn test_is_mobile() {
+        assert!(is_mobile("13800138000"));
+        assert!(is_mobile("+8613800138000"));
+    }
+
+    #[test]
+    fn test_is_possible_mobile() {
+        for number in [
+            "13800138000",
+            "+8613800138000",
+            "8613800138000",
+            "013800138000",
+        ] {
+            assert!(is_possible_mobile(number));
+            assert!(is_mobile(number));
+        }
+
+        assert!(is_possible_mobile("11800138000"));
+        assert!(!is_mobile("11800138000"));
+
+        assert!(!is_possible_mobile("1380013800"));
+        assert!(!is_possible_mobile("23800138000"));
+        assert!(!is_possible_mobile("1380013800a"));
+        assert!(!is_possible_mobile("+8713800138000"));
+        assert!(!is_possible_mobile(""));
+    }
+
+    #[test]
+    fn test_is_possible_phone() {
+        for number in ["110", "10086", "01012345678-1234", "+8613800138000"] {
+            assert!(is_possible_phone(number));
+            assert!(is_phone(number) || is_emergency(number));
+        }
+
+        assert!(is_possible_phone("12345678901234"));
+        assert!(!is_possible_phone("12"));
+        assert!(is_possible_phone("+ 86 13800138000"));
+        assert!(!is_possible_phone("电话"));
+        assert!(!is_possible_phone(""));
+    }
+
+    #[test]
+    #[cfg(not(feature = "loose"))]
+    fn test_is_mobile_unallocated_segment() {
+        for number in ["15400138000", "16000138000", "17900138000", "19400138000"] {
+            assert!(!is_mobile(number));
+            assert!(!is_mobile_strict(number, true, true));
+            assert!(!PhoneValidator::new().is_mobile(number));
+        }
+
+        assert!(is_mobile("13490138000"));
+        assert!(is_mobile("17000138000"));
+        assert!(is_mobile("19200138000"));
+    }
+
+    #[test]
+    fn test_validator_strictness() {
+        let loose = PhoneValidator::new().strictness(Strictness::Loose);
+        let standard = PhoneValidator::new().strictness(Strictness::Standard);
+        let strict = PhoneValidator::new().strictness(Strictness::Strict);
+
+        assert!(loose.is_mobile("15400138000"));
+        assert!(!standard.is_mobile("15400138000"));
+        assert!(!strict.is_mobile("15400138000"));
+        assert!(strict.is_mobile("13800138000"));
+
+        assert!(standard.is_telephone("03001234567"));
+        assert!(!strict.is_telephone("03001234567"));
+        assert!(standard.is_telephone("0101234567"));
+        assert!(!strict.is_telephone("0101234567"));
+        assert!(strict.is_telephone("01012345678-1234"));
+        assert!(strict.is_telephone("07551234567"));
+    }
+
+    #[test]
+    #[cfg(feature = "loose")]
+    fn test_is_mobile_loose() {
+        assert!(is_mobile("15400138000"));
+        assert!(is_mobile("19400138000"));
+        assert_eq!(MobileVendor::Others, get_vendor("15400138000"));
+    }
+
+    /// 修改全局配置的测试需要串行执行
+    static GLOBAL_CONFIG: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn test_set_mobile_second_digits() {
+        let _guard = GLOBAL_CONFIG.lock().unwrap();
+
+        assert!(!is_mobile("12800138000"));
+        assert!(!is_mobile_strict("12800138000", false, false));
+
+        set_mobile_second_digits(&[2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+        assert!(is_mobile("12800138000"));
+        assert!(is_mobile("+8612800138000"));
+        assert!(is_mobile_strict("12800138000", false, false));
+        assert!(PhoneValidator::new().is_mobile("12800138000"));
+        assert!(is_mobile("13800138000"));
+
+        set_mobile_second_digits(&DEFAULT_MOBILE_SECOND_DIGITS);
+
+        assert!(!is_mobile("12800138000"));
+        assert!(is_mobile("13800138000"));
+    }
+
+    #[test]
+    fn test_set_mobile_second_digits_concurrently() {
+        let _guard = GLOBAL_CONFIG.lock().unwrap();
+        let extended = [2, 3, 4, 5, 6, 7, 8, 9];
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..1000 {
+                        assert!(is_mobile("13800138000"));
+                        assert!(!is_mobile("11800138000"));
+                    }
+                });
+            }
+
+            scope.spawn(|| {
+                for i in 0..1000 {
+                    if i % 2 == 0 {
+                        set_mobile_second_digits(&extended);
+                    } else {
+                        set_mobile_second_digits(&DEFAULT_MOBILE_SECOND_DIGITS);
+                    }
+                }
+            });
+        });
+
+        set_mobile_second_digits(&DEFAULT_MOBILE_SECOND_DIGITS);
+
+        assert!(!is_mobile("12800138000"));
+        assert!(is_mobile("13800138000"));
+    }
+
+    #[test]
+    fn test_mobile_prefix_whitespace() {
+        let number = "+ 86 13800138000";
+
+        assert!(is_mobile(number));
+        assert!(is_mobile("+ 8613800138000"));
+        assert!(PhoneValidator::new().is_mobile(number));
+        assert!(is_possible_mobile(number));
+        assert!(is_possible_phone(number));
+        assert_eq!("13800138000", to_standard_format(number));
+        assert_eq!((PhoneType::Mobile, "1380013"), get_segment(number));
+        assert_eq!(
+            PhoneNumber::parse("13800138000"),
+            PhoneNumber::parse(number)
+        );
+        assert_eq!(
+            "13800138000",
+            MobileNumber::new(number).unwrap().standardized()
+        );
+        assert_eq!(
+            Some(PhoneType::Mobile),
+            explain(number).map(|e| e.phone_type)
+        );
+
+        for number in ["138 0013 8000", "+86 138 0013 8000", " 13800138000"] {
+            assert!(!is_mobile(number), "{}", number);
+            assert!(!is_possible_mobile(number), "{}", number);
+            assert_ne!(PhoneType::Mobile, classify(number), "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_is_mobile_non_ascii_digit() {
+        let validator = PhoneValidator::new();
+
+        for number in ["13８00138000", "1\u{0663}800138000", "+86138001380０0"] {
+            assert!(!is_mobile(number), "{}", number);
+            assert!(!is_mobile_strict(number, true, true), "{}", number);
+            assert!(!validator.is_mobile(number), "{}", number);
+            assert_ne!(PhoneType::Mobile, classify(number), "{}", number);
+            assert!(validate(number).is_err(), "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_set_max_input_len() {
+        let _guard = GLOBAL_CONFIG.lock().unwrap();
+        let huge = "1".repeat(1024 * 1024);
+        let long_idd = format!("00{}", "1".repeat(98));
+        let validator = PhoneValidator::new();
+
+        let start = std::time::Instant::now();
+        assert!(!is_mobile(&huge));
+        assert!(!is_telephone(&huge));
+        assert!(!is_service(&huge));
+        assert!(!is_idd(&huge));
+        assert!(!is_phone(&huge));
+        assert!(!is_phone_loose(&huge));
+        assert!(!validator.is_phone(&huge));
+        assert!(start.elapsed() < std::time::Duration::from_millis(100));
+
+        assert!(is_mobile("13800138000"));
+        assert!(is_phone("01012345678-1234"));
+        assert!(!is_idd(&long_idd));
+
+        set_max_input_len(128);
+        assert!(is_idd(&long_idd));
+
+        set_max_input_len(DEFAULT_MAX_INPUT_LEN);
+        assert!(!is_idd(&long_idd));
+    }
+
+    #[test]
+    fn test_is_mobile_strict() {
+        let numbers = [
+            "13800138000",
+            "013800138000",
+            "8613800138000",
+            "+8613800138000",
+            "+86013800138000",
+            "+13800138000",
+            "+ 86 13800138000",
+        ];
+
+        let expected = [
+            (
+                (false, false),
+                [true, false, false, false, false, false, false],
+            ),
+            ((true, false), [true, false, true, true, false, true, true]),
+            (
+                (false, true),
+                [true, true, false, false, false, false, false],
+            ),
+            ((true, true), [true, true, true, true, true, true, true]),
+        ];
+
+        for ((allow_country_code, allow_trunk_zero), results) in expected {
+            for (number, result) in numbers.iter().zip(results) {
+                assert_eq!(
+                    result,
+                    is_mobile_strict(number, allow_country_code, allow_trunk_zero),
+                    "{} {} {}",
+                    number,
+                    allow_country_code,
+                    allow_trunk_zero
+                );
+            }
+        }
+
+        for number in numbers {
+            assert_eq!(is_mobile(number), is_mobile_strict(number, true, true));
+        }
+    }
+
+    #[test]
+    fn test_is_telephone() {
+        assert!(is_telephone("01012345678"));
+        assert!(is_telephone("01012345678-1234"));
+        assert!(is_telephone("01012345678,1234"));
+        assert!(is_telephone("075512345678"));
+        assert!(is_telephone("075512345678-1234"));
+        assert!(is_telephone("075512345678,1234"));
+    }
+
+    #[test]
+    fn test_is_telephone_strict() {
+        assert!(is_telephone_strict("01012345678"));
+        assert!(is_telephone_strict("+8601012345678-1234"));
+        assert!(is_telephone_strict("02712345678"));
+        assert!(is_telephone_strict("075512345678,1234"));
+        assert!(is_telephone_strict("09991234567"));
+        assert!(!is_telephone_strict("02612345678"));
+        assert!(!is_telephone_strict("03001234567"));
+        assert!(!is_telephone_strict("13800138000"));
+
+        assert!(is_telephone("02612345678"));
+        assert!(is_telephone("03001234567"));
+    }
+
+    #[test]
+    fn test_is_service() {
+        assert!(is_service("10000000"));
+        assert!(is_service("10086"));
+        assert!(is_service("95588"));
+    }
+
+    #[test]
+    fn test_is_service_extended() {
+        assert!(is_service_extended("95588"));
+        assert!(is_service_extended("955801"));
+        assert!(is_service_extended("9558012"));
+        assert!(is_service_extended("96166"));
+        assert!(is_service_extended("10086"));
+        assert!(!is_service_extended("95580123"));
+        assert!(!is_service_extended("9558"));
+        assert!(!is_service_extended("975801"));
+    }
+
+    #[test]
+    fn test_is_service_intl() {
+        assert!(is_service_intl("10086"));
+        assert!(is_service_intl("+8610086"));
+        assert!(is_service_intl("8610086"));
+        assert!(is_service_intl("+8695588"));
+        assert_eq!(is_service("10086"), is_service_intl("+8610086"));
+        assert!(!is_service_intl("+10086"));
+        assert!(!is_service_intl("+8613800138000"));
+    }
+
+    #[test]
+    fn test_is_idd() {
+        assert!(is_idd("0012345678"));
+        assert!(is_idd("008512345678"));
+    }
+
+    #[test]
+    fn test_is_emergency() {
+        for number in ["110", "112", "119", "120", "122", "999"] {
+            assert!(is_emergency(number));
+            assert_eq!(PhoneType::Emergency, classify(number));
+        }
+
+        assert!(!is_emergency("114"));
+        assert!(!is_emergency("1100"));
+        assert!(!is_service("110"));
+        assert!(PhoneValidator::new().is_emergency("120"));
+        assert_eq!((PhoneType::Emergency, "119"), get_segment("119"));
+    }
+
+    #[test]
+    fn test_is_business() {
+        assert!(is_business("4001234567"));
+        assert!(is_business("8001234567"));
+        assert!(is_business("400-123-4567"));
+        assert!(is_business("400-1234567"));
+
+        assert!(!is_business("4011234567"));
+        assert!(!is_business("400123456"));
+        assert!(!is_business("400--123-4567"));
+        assert!(PhoneValidator::new().is_business("800-123-4567"));
+
+        assert_eq!(PhoneType::Business, classify("400-123-4567"));
+        assert_eq!((PhoneType::Business, "400"), get_segment("400-123-4567"));
+        assert_eq!("4001234567", normalize("400-123-4567"));
+    }
+
+    #[test]
+    fn test_sms_gateway() {
+        assert!(is_sms_gateway("10690123"));
+        assert!(is_sms_gateway("1069012345678"));
+        assert!(is_sms_gateway("106575201234567890"));
+
+        assert!(!is_sms_gateway("1069012"));
+        assert!(!is_sms_gateway("1064812345678"));
+        assert!(!is_sms_gateway("13800138000"));
+
+        assert_eq!(Some("10690"), sms_gateway_class("1069012345678"));
+        assert_eq!(Some("10691"), sms_gateway_class("10691123456"));
+        assert_eq!(Some("10657"), sms_gateway_class("106575201234567890"));
+        assert_eq!(None, sms_gateway_class("1064812345678"));
+    }
+
+    #[test]
+    fn test_service_provider() {
+        assert_eq!(Some("招商银行"), service_provider("95555"));
+        assert_eq!(Some("顺丰速运"), service_provider("95338"));
+        assert_eq!(Some("反诈预警劝阻专线"), service_provider("96110"));
+        assert_eq!(None, service_provider("95000"));
+        assert_eq!(None, service_provider(""));
+    }
+
+    #[test]
+    fn test_short_code_name() {
+        assert_eq!(Some("火警"), short_code_name("119"));
+        assert_eq!(Some("查号台"), short_code_name("114"));
+        assert_eq!(Some("政务服务便民热线"), short_code_name("12345"));
+        assert_eq!(None, short_code_name("12346"));
+        assert_eq!(None, short_code_name(""));
+
+        for number in ["114", "121", "12580"] {
+            assert!(is_service(number));
+            assert!(PhoneValidator::new().is_service(number));
+            assert_eq!(PhoneType::Service, classify(number));
+        }
+
+        assert!(!is_service("113"));
+        assert_eq!(PhoneType::Others, classify("113"));
+    }
+
+    #[test]
+    fn test_is_iot() {
+        assert!(is_iot("1440123456789"));
+        assert!(is_iot("1480123456789"));
+        assert!(is_iot("+861400123456789"));
+        assert!(is_iot("1064812345678"));
+
+        assert!(is_iot("14801234567"));
+        assert!(!is_iot("1450123456789"));
+        assert!(!is_iot("13800138000"));
+        assert!(!is_iot("14501234567"));
+        assert!(!is_phone("1440123456789"));
+
+        assert_eq!(PhoneType::Iot, classify("1440123456789"));
+        assert_eq!((PhoneType::Iot, "10648"), get_segment("+861064812345678"));
+        assert_eq!((PhoneType::Iot, "144"), get_segment("1440123456789"));
+        assert!(PhoneValidator::new().is_iot("1064812345678"));
+    }
+
+    #[test]
+    fn test_iot_segments_are_not_mobile() {
+        let validator = PhoneValidator::new().strictness(Strictness::Loose);
+
+        for number in ["14612345678", "14801234567", "+8617212345678"] {
+            assert!(is_iot(number), "{}", number);
+            assert!(!is_mobile(number), "{}", number);
+            assert!(!is_mobile_strict(number, true, true), "{}", number);
+            assert!(!validator.is_mobile(number), "{}", number);
+            assert_eq!(PhoneType::Iot, classify(number), "{}", number);
+            assert_eq!(MobileVendor::Others, get_vendor(number), "{}", number);
+        }
+
+        assert_eq!((PhoneType::Iot, "172"), get_segment("17212345678"));
+        assert!(is_mobile("14912345678"));
+    }
+
+    #[test]
+    fn test_is_phone() {
         assert!(is_phone("13800138000"));
         assert!(is_phone("+8613800138000"));
         assert!(is_phone("01012345678"));
@@ -393,6 +2873,168 @@ mod tests {
         assert!(is_phone("008512345678"));
     }
 
+    #[test]
+    fn test_is_phone_excludes_classified_types() {
+        let validator = PhoneValidator::new();
+
+        for (number, phone_type) in [
+            ("110", PhoneType::Emergency),
+            ("4001234567", PhoneType::Business),
+            ("400-123-4567", PhoneType::Business),
+            ("1440012345678", PhoneType::Iot),
+        ] {
+            assert_eq!(phone_type, classify(number), "{}", number);
+            assert!(!is_phone(number), "{}", number);
+            assert!(!validator.is_phone(number), "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_is_test_number() {
+        assert!(is_test_number("13800138000"));
+        assert!(is_test_number("+8613800138000"));
+        assert!(!is_test_number("13912345678"));
+        assert!(!is_test_number("01012345678"));
+    }
+
+    #[test]
+    fn test_looks_suspicious() {
+        assert!(looks_suspicious("13333333333"));
+        assert!(looks_suspicious("13888888888"));
+        assert!(looks_suspicious("13012345678"));
+        assert!(looks_suspicious("+8613087654321"));
+        assert!(!looks_suspicious("13800138000"));
+        assert!(!looks_suspicious("01012345678"));
+    }
+
+    #[test]
+    fn test_looks_like_imsi() {
+        assert!(looks_like_imsi("460001234567890"));
+        assert!(!looks_like_imsi("46000123456789"));
+        assert!(!looks_like_imsi("001234567890123"));
+        assert!(!looks_like_imsi("13800138000"));
+    }
+
+    #[test]
+    fn test_looks_like_iccid() {
+        assert!(looks_like_iccid("89860012345678901234"));
+        assert!(looks_like_iccid("8986001234567890123"));
+        assert!(!looks_like_iccid("898600123456789012345"));
+        assert!(!looks_like_iccid("460001234567890"));
+        assert!(!looks_like_iccid("13800138000"));
+    }
+
+    #[test]
+    fn test_is_phone_order_independent() {
+        let _guard = GLOBAL_CONFIG.lock().unwrap();
+        let prefixes = ["", "+", "86", "+86", "0", "00", "+860", "8600"];
+        let bodies = [
+            "13800138000",
+            "12800138000",
+            "1380013800",
+            "01012345678",
+            "1012345678",
+            "075512345678",
+            "01012345678-1234",
+            "01012345678,12",
+            "10000000",
+            "10086",
+            "95588",
+            "9558",
+            "12345678",
+            "8512345678",
+            "",
+            "abc",
+        ];
+
+        for prefix in prefixes {
+            for body in bodies {
+                let number = format!("{}{}", prefix, body);
+                let naive = is_mobile(&number)
+                    || is_telephone(&number)
+                    || is_service(&number)
+                    || is_idd(&number);
+
+                assert_eq!(naive, is_phone(&number), "{}", number);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_phone_loose() {
+        assert!(is_phone_loose("13800138000"));
+        assert!(is_phone_loose("1795113800138000"));
+        assert!(is_phone_loose("1259301012345678"));
+        assert!(!is_phone_loose("1795112"));
+        assert!(!is_phone_loose("17951abc"));
+    }
+
+    #[test]
+    fn test_normalize_digits() {
+        assert_eq!("13800138000", normalize_digits("１３８００１３８０００"));
+        assert_eq!(
+            "+8613800138000",
+            normalize_digits("＋８６１３８００１３８０００")
+        );
+        assert_eq!(
+            "01012345678-1234",
+            normalize_digits("０１０１２３４５６７８－１２３４")
+        );
+        assert_eq!("13800138000", normalize_digits("١٣٨٠٠١٣٨٠٠٠"));
+        assert_eq!("13800138000", normalize_digits("۱۳۸۰۰۱۳۸۰۰۰"));
+        assert_eq!("138abc", normalize_digits("138abc"));
+        assert_eq!(
+            "13800138000",
+            normalize_digits("\u{FEFF}138\u{200B}0013\u{200D}8000")
+        );
+        assert_eq!(
+            "13800138000",
+            normalize_digits("\u{202A}13800138000\u{202C}")
+        );
+        assert!(!is_mobile("138\u{200B}00138000"));
+        assert!(is_mobile(&normalize_digits("138\u{200B}00138000")));
+        assert!(is_phone_loose("138\u{200B}00138000"));
+
+        let spaced = normalize_digits("+ 86 138 0013 8000");
+        assert_eq!("+8613800138000", spaced);
+        assert!(is_mobile(&spaced));
+        assert_eq!("13800138000", to_standard_format(&spaced));
+        assert!(is_phone_loose("+ 86 138 0013 8000"));
+        assert_eq!(
+            "13800138000",
+            to_standard_format(&normalize("+ 86 138 0013 8000"))
+        );
+        assert_eq!("13800138000", standardize_full("+ 86 138 0013 8000"));
+        assert_eq!("13800138000", to_standard_format("86 13800138000"));
+        assert!(is_phone_loose("＋８６　１３８　００１３　８０００"));
+
+        assert!(is_mobile(&normalize_digits("１３８００１３８０００")));
+        assert!(is_phone_loose("１３８００１３８０００"));
+    }
+
+    #[test]
+    fn test_strip_idd_prefix() {
+        assert_eq!("13800138000", strip_idd_prefix("1795113800138000"));
+        assert_eq!("13800138000", strip_idd_prefix("1259313800138000"));
+        assert_eq!("01012345678", strip_idd_prefix("1019301012345678"));
+        assert_eq!("13800138000", strip_idd_prefix("1180813800138000"));
+        assert_eq!("13800138000", strip_idd_prefix("1791113800138000"));
+        assert_eq!("13800138000", strip_idd_prefix("13800138000"));
+        assert_eq!("17951234567", strip_idd_prefix("17951234567"));
+    }
+
+    #[test]
+    fn test_strip_gateway_prefix() {
+        assert_eq!("13800138000", strip_gateway_prefix("10613800138000"));
+        assert_eq!("13800138000", strip_gateway_prefix("1252013800138000"));
+        assert_eq!("13800138000", strip_gateway_prefix("13800138000"));
+        assert_eq!("1069012345", strip_gateway_prefix("1069012345"));
+
+        assert!(!is_phone("10613800138000"));
+        assert!(is_phone_loose("10613800138000"));
+        assert!(is_phone_loose("1252013800138000"));
+    }
+
     #[test]
     fn test_to_standard_format() {
         assert_eq!("13800138000", to_standard_format("13800138000"));
@@ -409,6 +3051,347 @@ mod tests {
         assert_eq!("10086", to_standard_format("10086"));
         assert_eq!("95588", to_standard_format("95588"));
         assert_eq!("008512345678", to_standard_format("008512345678"));
+        assert_eq!("13800138000", to_standard_format("868613800138000"));
+    }
+
+    #[test]
+    fn test_to_standard_format_idempotent() {
+        for number in [
+            "13800138000",
+            "013800138000",
+            "8613800138000",
+            "+8613800138000",
+            "+86013800138000",
+            "868613800138000",
+            "01012345678",
+            "+8601012345678",
+            "01012345678-1234",
+            "01012345678,1234",
+            "075512345678",
+            "075512345678-1234",
+            "075512345678,1234",
+            "10000000",
+            "10086",
+            "95588",
+            "008512345678",
+        ] {
+            let once = to_standard_format(number);
+
+            assert_eq!(once, to_standard_format(once), "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_is_telephone_length_consistent() {
+        assert!(is_telephone_length_consistent("01012345678"));
+        assert!(is_telephone_length_consistent("+8601012345678-1234"));
+        assert!(is_telephone_length_consistent("075512345678"));
+        assert!(is_telephone_length_consistent("08731234567"));
+        assert!(!is_telephone_length_consistent("0101234567"));
+        assert!(!is_telephone_length_consistent("010123456789"));
+        assert!(!is_telephone_length_consistent("0755123456"));
+        assert!(!is_telephone_length_consistent("13800138000"));
+    }
+
+    #[test]
+    fn test_format_display() {
+        assert_eq!(
+            Some(String::from("+86 138 0013 8000")),
+            format_display("13800138000", DisplayStyle::International)
+        );
+        assert_eq!(
+            Some(String::from("138-0013-8000")),
+            format_display("+8613800138000", DisplayStyle::National)
+        );
+        assert_eq!(
+            Some(String::from("13800138000")),
+            format_display("013800138000", DisplayStyle::Compact)
+        );
+
+        assert_eq!(
+            Some(String::from("+86 10 1234 5678")),
+            format_display("01012345678", DisplayStyle::International)
+        );
+        assert_eq!(
+            Some(String::from("+86 755 1234 5678 ext. 1234")),
+            format_display("075512345678,1234", DisplayStyle::International)
+        );
+        assert_eq!(
+            Some(String::from("010-12345678-1234")),
+            format_display("01012345678-1234", DisplayStyle::National)
+        );
+        assert_eq!(
+            Some(String::from("01012345678-1234")),
+            format_display("01012345678,1234", DisplayStyle::Compact)
+        );
+
+        assert_eq!(None, format_display("10086", DisplayStyle::National));
+        assert_eq!(
+            None,
+            format_display("0012345678", DisplayStyle::International)
+        );
+        assert_eq!(None, format_display("008512345678", DisplayStyle::National));
+        assert_eq!(None, format_display("abc", DisplayStyle::Compact));
+    }
+
+    #[test]
+    fn test_standardize_full() {
+        assert_eq!("01012345678-1234", standardize_full("01012345678-1234"));
+        assert_eq!("01012345678-1234", standardize_full("01012345678,1234"));
+        assert_eq!("01012345678-1234", standardize_full("+8601012345678,1234"));
+        assert_eq!("01012345678", standardize_full("01012345678"));
+        assert_eq!("13800138000", standardize_full("+8613800138000"));
+        assert!(is_telephone(&standardize_full("075512345678,1234")));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!("13800138000", normalize("138-0013-8000"));
+        assert_eq!("01012345678", normalize("(010) 1234 5678"));
+        assert_eq!("01012345678", normalize("（010）1234－5678"));
+        assert_eq!("+8613800138000", normalize("+86 138.0013.8000"));
+        assert_eq!("01012345678,1234", normalize("010-1234-5678,1234"));
+        assert_eq!("01012345678-1234", normalize("01012345678-1234"));
+        assert_eq!("01012345678-1234", normalize("(010) 1234 5678-1234"));
+        assert!(is_phone(&normalize("010-12345678-1234")));
+
+        assert!(matches!(normalize("13800138000"), Cow::Borrowed(_)));
+        assert!(matches!(normalize("138 0013 8000"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_split_extension() {
+        assert_eq!(
+            ("01012345678", Some("1234")),
+            split_extension("01012345678-1234")
+        );
+        assert_eq!(
+            ("075512345678", Some("1234567")),
+            split_extension("+86075512345678,1234567")
+        );
+        assert_eq!(("01012345678", None), split_extension("01012345678"));
+        assert_eq!(("13800138000", None), split_extension("+8613800138000"));
+        assert_eq!(("138-0013", None), split_extension("138-0013"));
+    }
+
+    #[test]
+    fn test_join_extension() {
+        assert_eq!(
+            "01012345678-1234",
+            join_extension("01012345678", Some("1234"))
+        );
+        assert_eq!("01012345678", join_extension("01012345678", None));
+
+        for number in ["01012345678-1234", "01012345678", "13800138000"] {
+            let (main, extension) = split_extension(number);
+
+            assert_eq!(number, join_extension(main, extension));
+        }
+    }
+
+    #[test]
+    fn test_split_concatenated() {
+        assert_eq!(
+            Some(("13800138000", "01012345678")),
+            split_concatenated("1380013800001012345678")
+        );
+        assert_eq!(
+            Some(("01012345678", "13800138000")),
+            split_concatenated("0101234567813800138000")
+        );
+        assert_eq!(
+            Some(("13800138000", "10086")),
+            split_concatenated("1380013800010086")
+        );
+        assert_eq!(None, split_concatenated("13800138000"));
+        assert_eq!(None, split_concatenated("电话号码"));
+        assert_eq!(None, split_concatenated(""));
+    }
+
+    #[test]
+    fn test_index_key() {
+        for number in [
+            "13800138000",
+            "+8613800138000",
+            "8613800138000",
+            "013800138000",
+        ] {
+            assert_eq!(Some(String::from("00138000")), index_key(number));
+        }
+
+        assert_eq!(Some(String::from("12345678")), index_key("01012345678"));
+        assert_eq!(
+            Some(String::from("12345678")),
+            index_key("+8601012345678-1234")
+        );
+        assert_eq!(Some(String::from("10086")), index_key("10086"));
+        assert_eq!(Some(String::from("10000000")), index_key("10000000"));
+        assert_eq!(None, index_key("abc"));
+    }
+
+    #[test]
+    fn test_area_code_city() {
+        assert_eq!(
+            Some(&City {
+                area_code: "010",
+                province: "北京",
+                name: "北京",
+                admin_code: "110000",
+            }),
+            area_code_city("010")
+        );
+        assert_eq!(Some("新疆"), area_code_city("0999").map(|c| c.province));
+        assert_eq!(Some("青海"), area_code_city("0973").map(|c| c.province));
+        assert_eq!(None, area_code_city("0300"));
+        assert_eq!(None, area_code_city("755"));
+
+        for city in &area_code::AREA_CODES {
+            assert_eq!(6, city.admin_code.len());
+            assert_eq!(Some(city), area_code_city(city.area_code));
+        }
+    }
+
+    #[test]
+    fn test_split_area_code() {
+        assert_eq!(Some(("010", "12345678")), split_area_code("01012345678"));
+        assert_eq!(Some(("021", "12345678")), split_area_code("02112345678"));
+        assert_eq!(
+            Some(("0755", "1234567")),
+            split_area_code("+8607551234567,1234")
+        );
+        assert_eq!(Some(("0999", "1234567")), split_area_code("09991234567"));
+
+        assert_eq!(None, split_area_code("03001234567"));
+        assert_eq!(None, split_area_code("008512345678"));
+        assert_eq!(None, split_area_code("13800138000"));
+        assert_eq!(None, split_area_code(""));
+    }
+
+    #[test]
+    fn test_split_country_code() {
+        assert_eq!(
+            Some(("852", "12345678")),
+            split_country_code("0085212345678")
+        );
+        assert_eq!(
+            Some(("81", "312345678")),
+            split_country_code("0081312345678")
+        );
+        assert_eq!(
+            Some(("7", "4951234567")),
+            split_country_code("0074951234567")
+        );
+        assert_eq!(
+            Some(("1", "2025550123")),
+            split_country_code("0012025550123")
+        );
+
+        assert_eq!(None, split_country_code("008512345678"));
+        assert_eq!(None, split_country_code("13800138000"));
+        assert_eq!(None, split_country_code(""));
+    }
+
+    #[test]
+    fn test_idd_country() {
+        assert_eq!(Some("HK"), idd_country("0085212345678").and_then(|c| c.iso));
+        assert_eq!(
+            Some("GB"),
+            idd_country("00442071234567").and_then(|c| c.iso)
+        );
+        assert_eq!(Some("US"), idd_country("0012025550123").and_then(|c| c.iso));
+        assert_eq!(
+            Some("Japan"),
+            idd_country("0081312345678").map(|c| c.name_en)
+        );
+        assert_eq!(None, idd_country("0080012345678").and_then(|c| c.iso));
+        assert_eq!(None, idd_country("008512345678"));
+        assert_eq!(None, idd_country("13800138000"));
+
+        for country in &country_code::COUNTRY_CODES {
+            assert_eq!(Some(country), calling_code_country(country.calling_code));
+        }
+    }
+
+    #[test]
+    fn test_get_segment_idd() {
+        assert_eq!((PhoneType::Idd, "852"), get_segment("0085212345678"));
+        assert_eq!((PhoneType::Idd, "44"), get_segment("00442071234567"));
+        assert_eq!((PhoneType::Idd, "8512"), get_segment("008512345678"));
+    }
+
+    #[test]
+    fn test_try_get_segment() {
+        let _guard = GLOBAL_CONFIG.lock().unwrap();
+
+        assert_eq!(
+            Ok((PhoneType::Mobile, "1380013")),
+            try_get_segment("+8613800138000")
+        );
+        assert_eq!(
+            Ok((PhoneType::Tel, "0755")),
+            try_get_segment("075512345678")
+        );
+        assert_eq!(
+            Ok((PhoneType::Idd, "8512")),
+            try_get_segment("008512345678")
+        );
+        assert_eq!(Ok((PhoneType::Service, "10086")), try_get_segment("10086"));
+
+        assert_eq!(Err(PhoneError::Empty), try_get_segment(""));
+        assert_eq!(
+            Err(PhoneError::Invalid(String::from("12"))),
+            try_get_segment("12")
+        );
+        assert_eq!(
+            Err(PhoneError::TooLong(100)),
+            try_get_segment(&"1".repeat(100))
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let _guard = GLOBAL_CONFIG.lock().unwrap();
+
+        assert_eq!(Ok(PhoneType::Mobile), validate("13800138000"));
+        assert_eq!(Ok(PhoneType::Tel), validate("+86075512345678-1234"));
+        assert_eq!(Ok(PhoneType::Service), validate("95588"));
+
+        assert_eq!(Err(ValidationError::Empty), validate(""));
+        assert_eq!(
+            Err(ValidationError::TooLong(100)),
+            validate(&"1".repeat(100))
+        );
+        assert_eq!(
+            Err(ValidationError::IllegalCharacter('零')),
+            validate("138零0138000")
+        );
+        assert_eq!(Err(ValidationError::BadLength(3)), validate("138"));
+        assert_eq!(
+            Err(ValidationError::UnknownAreaCode(String::from("0300"))),
+            validate("03001234567")
+        );
+        assert_eq!(
+            "区号 0300 不存在",
+            ValidationError::UnknownAreaCode(String::from("0300")).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "loose"))]
+    fn test_validate_unallocated_prefix() {
+        assert_eq!(
+            Err(ValidationError::UnallocatedPrefix(String::from("154"))),
+            validate("+8615400138000")
+        );
+    }
+
+    #[test]
+    fn test_phone_error_display() {
+        assert_eq!("号码为空", PhoneError::Empty.to_string());
+        assert_eq!(
+            "12 不是正常的电话号码",
+            PhoneError::Invalid(String::from("12")).to_string()
+        );
     }
 
     #[test]
@@ -419,5 +3402,242 @@ mod tests {
         assert_eq!((PhoneType::Tel, "010"), get_segment("01012345678"));
         assert_eq!((PhoneType::Tel, "027"), get_segment("02712345678"));
         assert_eq!((PhoneType::Tel, "0755"), get_segment("075512345678"));
+        assert_eq!(
+            (PhoneType::Mobile, "1380013"),
+            get_segment("+8613800138000")
+        );
+        assert_eq!((PhoneType::Mobile, "1380013"), get_segment("013800138000"));
+        assert_eq!((PhoneType::Mobile, "1380013"), get_segment("8613800138000"));
+        assert_eq!(
+            (PhoneType::Mobile, "1380013"),
+            get_segment("86013800138000")
+        );
+        assert_eq!((PhoneType::Tel, "010"), get_segment("+8601012345678"));
+        assert_eq!((PhoneType::Tel, "12"), get_segment("12"));
+
+        // 非数字的输入不会因切片落在字符中间而 panic
+        get_segment("电话号码");
+        assert!(try_get_segment("电话号码").is_err());
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_validate_csv_column() {
+        let data = "name,phone\nfoo, 13800138000\nbar,garbage\nbaz,01012345678-1234\nqux\n";
+
+        assert_eq!(
+            vec![(0, false), (1, true), (2, false), (3, true), (4, false)],
+            validate_csv_column(data.as_bytes(), 1).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_scan() {
+        let text =
+            "手机：+8613800138000，座机 01012345678-1234, 客服10086；国际 008512345678，编号 123。";
+
+        assert_eq!(
+            vec![
+                (PhoneType::Mobile, "+8613800138000"),
+                (PhoneType::Tel, "01012345678-1234"),
+                (PhoneType::Service, "10086"),
+                (PhoneType::Idd, "008512345678"),
+            ],
+            scan(text).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                (PhoneType::Mobile, "13800138000"),
+                (PhoneType::Tel, "01012345678"),
+            ],
+            scan("13800138000,01012345678").collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                (PhoneType::Tel, "010-12345678"),
+                (PhoneType::Tel, "0755-12345678-1234"),
+                (PhoneType::Mobile, "13800138000"),
+            ],
+            scan("电话 010-12345678，0755-12345678-1234 或 13800138000").collect::<Vec<_>>()
+        );
+        assert_eq!(0, scan("没有号码").count());
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(PhoneType::Mobile, classify("+8613800138000"));
+        assert_eq!(PhoneType::Idd, classify("008512345678"));
+        assert_eq!(PhoneType::Service, classify("10086"));
+        assert_eq!(PhoneType::Service, classify("95588"));
+        assert_eq!(PhoneType::Tel, classify("01012345678"));
+        assert_eq!(PhoneType::Others, classify(""));
+        assert_eq!(PhoneType::Others, classify("abc"));
+
+        let validator = PhoneValidator::new();
+        for number in ["008512345678", "13800138000", "10086", "01012345678", "abc"] {
+            assert_eq!(classify(number), validator.classify(number));
+        }
+    }
+
+    #[test]
+    fn test_classify_all() {
+        assert_eq!(
+            vec![PhoneType::Idd, PhoneType::Tel],
+            classify_all("008512345678")
+        );
+        assert_eq!(vec![PhoneType::Mobile], classify_all("13800138000"));
+        assert_eq!(vec![PhoneType::Service], classify_all("10086"));
+        assert!(classify_all("abc").is_empty());
+
+        for number in ["008512345678", "13800138000", "10086", "01012345678"] {
+            assert_eq!(detect_type(number), classify_all(number).into_iter().next());
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(
+            Some(Explanation {
+                phone_type: PhoneType::Mobile,
+                pattern: "mobile",
+                range: 0..14,
+            }),
+            explain("+8613800138000")
+        );
+        assert_eq!(
+            Some(Explanation {
+                phone_type: PhoneType::Idd,
+                pattern: "idd",
+                range: 0..12,
+            }),
+            explain("008512345678")
+        );
+        assert_eq!(
+            Some("telephone"),
+            explain("01012345678-1234").map(|e| e.pattern)
+        );
+        assert_eq!(Some("service"), explain("10086").map(|e| e.pattern));
+        assert_eq!(
+            Some(Explanation {
+                phone_type: PhoneType::Service,
+                pattern: "short_code",
+                range: 0..3,
+            }),
+            explain("114")
+        );
+        assert_eq!(None, explain("abc"));
+    }
+
+    #[test]
+    fn test_get_vendor() {
+        assert_eq!(MobileVendor::Mobile, get_vendor("13800138000"));
+        assert_eq!(MobileVendor::Mobile, get_vendor("+8615000138000"));
+        assert_eq!(MobileVendor::Unicom, get_vendor("13000138000"));
+        assert_eq!(MobileVendor::Unicom, get_vendor("18600138000"));
+        assert_eq!(MobileVendor::Telecom, get_vendor("18900138000"));
+        assert_eq!(MobileVendor::Telecom, get_vendor("013300138000"));
+        assert_eq!(MobileVendor::Cbn, get_vendor("19200138000"));
+
+        assert_eq!(MobileVendor::Mobile, get_vendor("13400138000"));
+        assert_eq!(MobileVendor::Telecom, get_vendor("13490138000"));
+        assert_eq!(MobileVendor::Telecom, get_vendor("17000138000"));
+        assert_eq!(MobileVendor::Mobile, get_vendor("17030138000"));
+        assert_eq!(MobileVendor::Unicom, get_vendor("17090138000"));
+
+        assert_eq!(MobileVendor::Others, get_vendor("15400138000"));
+        assert_eq!(MobileVendor::Others, get_vendor("01012345678"));
+        assert_eq!(MobileVendor::Others, get_vendor(""));
+    }
+
+    #[test]
+    fn test_segment_vendor_mismatch() {
+        assert!(segment_vendor_mismatch(
+            "13800138000",
+            MobileVendor::Telecom
+        ));
+        assert!(segment_vendor_mismatch(
+            "+8618900138000",
+            MobileVendor::Unicom
+        ));
+        assert!(!segment_vendor_mismatch(
+            "13800138000",
+            MobileVendor::Mobile
+        ));
+        assert!(!segment_vendor_mismatch(
+            "+8618900138000",
+            MobileVendor::Telecom
+        ));
+    }
+
+    #[test]
+    fn test_is_virtual() {
+        assert!(is_virtual("17001234567"));
+        assert!(is_virtual("+8617101234567"));
+        assert!(is_virtual("16201234567"));
+        assert!(is_virtual("16501234567"));
+        assert!(is_virtual("16701234567"));
+
+        assert!(!is_virtual("13800138000"));
+        assert!(!is_virtual("17301234567"));
+        assert!(!is_virtual("01012345678"));
+        assert!(!is_virtual("170"));
+
+        assert_eq!(MobileVendor::Telecom, get_vendor("16201234567"));
+        assert_eq!(MobileVendor::Mobile, get_vendor("16501234567"));
+        assert_eq!(MobileVendor::Unicom, get_vendor("17101234567"));
+    }
+
+    #[test]
+    fn test_is_satellite() {
+        assert!(is_satellite("17490123456"));
+        assert!(is_satellite("+8617490123456"));
+        assert!(is_satellite("13490123456"));
+
+        assert!(!is_satellite("17400123456"));
+        assert!(!is_satellite("13800138000"));
+        assert!(!is_satellite("1749"));
+        assert!(is_mobile("17490123456"));
+    }
+
+    #[test]
+    fn test_info() {
+        assert_eq!(
+            Ok(PhoneInfo {
+                phone_type: PhoneType::Tel,
+                vendor: MobileVendor::Others,
+                segment: String::from("0755"),
+                standard: String::from("075512345678"),
+            }),
+            info("+86075512345678-1234")
+        );
+        assert_eq!(
+            r#"{"phone_type":"MOBILE","vendor":"10010 联通","segment":"1860013","standard":"18600138000"}"#,
+            serde_json::to_string(&info("18600138000").unwrap()).unwrap()
+        );
+        assert_eq!(Err(PhoneError::Empty), info(""));
+    }
+
+    #[test]
+    fn test_mobile_segment3() {
+        assert_eq!(Some("138"), mobile_segment3("13800138000"));
+        assert_eq!(Some("138"), mobile_segment3("+8613800138000"));
+        assert_eq!(None, mobile_segment3("01012345678"));
+        assert_eq!(None, mobile_segment3("10086"));
+    }
+
+    #[test]
+    fn test_safe_slice() {
+        assert_eq!("138", safe_slice("13800138000", 0, 3));
+        assert_eq!("13800138000", safe_slice("13800138000", 0, 100));
+        assert_eq!("", safe_slice("13800138000", 100, 200));
+        assert_eq!("", safe_slice("13800138000", 5, 2));
+
+        // "电话" 每个字符占 3 个字节
+        assert_eq!("电", safe_slice("电话", 0, 4));
+        assert_eq!("电", safe_slice("电话", 1, 5));
+        assert_eq!("话", safe_slice("电话", 3, 6));
+        assert_eq!("", safe_slice("电话", 1, 2));
+        assert_eq!("+86", safe_slice("+86电话", 0, 4));
     }
 }
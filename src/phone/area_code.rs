@@ -0,0 +1,359 @@
+//! 中国大陆固定电话区号
+
+/// 固定电话区号对应的城市
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct City {
+    /// 区号，如 `0755`
+    pub area_code: &'static str,
+    /// 省级行政区，如 `广东`
+    pub province: &'static str,
+    /// 城市，如 `深圳`
+    pub name: &'static str,
+    /// 行政区划代码，如 `440300`
+    pub admin_code: &'static str,
+}
+
+const fn city(
+    area_code: &'static str,
+    province: &'static str,
+    name: &'static str,
+    admin_code: &'static str,
+) -> City {
+    City {
+        area_code,
+        province,
+        name,
+        admin_code,
+    }
+}
+
+/// 中国大陆固定电话区号，按区号的字典序排列以便二分查找
+///
+/// 多个城市共用同一区号时，仅记录区号所属的主要城市
+pub(crate) static AREA_CODES: [City; 325] = [
+    city("010", "北京", "北京", "110000"),
+    city("020", "广东", "广州", "440100"),
+    city("021", "上海", "上海", "310000"),
+    city("022", "天津", "天津", "120000"),
+    city("023", "重庆", "重庆", "500000"),
+    city("024", "辽宁", "沈阳", "210100"),
+    city("025", "江苏", "南京", "320100"),
+    city("027", "湖北", "武汉", "420100"),
+    city("028", "四川", "成都", "510100"),
+    city("029", "陕西", "西安", "610100"),
+    city("0310", "河北", "邯郸", "130400"),
+    city("0311", "河北", "石家庄", "130100"),
+    city("0312", "河北", "保定", "130600"),
+    city("0313", "河北", "张家口", "130700"),
+    city("0314", "河北", "承德", "130800"),
+    city("0315", "河北", "唐山", "130200"),
+    city("0316", "河北", "廊坊", "131000"),
+    city("0317", "河北", "沧州", "130900"),
+    city("0318", "河北", "衡水", "131100"),
+    city("0319", "河北", "邢台", "130500"),
+    city("0335", "河北", "秦皇岛", "130300"),
+    city("0349", "山西", "朔州", "140600"),
+    city("0350", "山西", "忻州", "140900"),
+    city("0351", "山西", "太原", "140100"),
+    city("0352", "山西", "大同", "140200"),
+    city("0353", "山西", "阳泉", "140300"),
+    city("0354", "山西", "晋中", "140700"),
+    city("0355", "山西", "长治", "140400"),
+    city("0356", "山西", "晋城", "140500"),
+    city("0357", "山西", "临汾", "141000"),
+    city("0358", "山西", "吕梁", "141100"),
+    city("0359", "山西", "运城", "140800"),
+    city("0370", "河南", "商丘", "411400"),
+    city("0371", "河南", "郑州", "410100"),
+    city("0372", "河南", "安阳", "410500"),
+    city("0373", "河南", "新乡", "410700"),
+    city("0374", "河南", "许昌", "411000"),
+    city("0375", "河南", "平顶山", "410400"),
+    city("0376", "河南", "信阳", "411500"),
+    city("0377", "河南", "南阳", "411300"),
+    city("0379", "河南", "洛阳", "410300"),
+    city("0391", "河南", "焦作", "410800"),
+    city("0392", "河南", "鹤壁", "410600"),
+    city("0393", "河南", "濮阳", "410900"),
+    city("0394", "河南", "周口", "411600"),
+    city("0395", "河南", "漯河", "411100"),
+    city("0396", "河南", "驻马店", "411700"),
+    city("0398", "河南", "三门峡", "411200"),
+    city("0410", "辽宁", "铁岭", "211200"),
+    city("0411", "辽宁", "大连", "210200"),
+    city("0412", "辽宁", "鞍山", "210300"),
+    city("0413", "辽宁", "抚顺", "210400"),
+    city("0414", "辽宁", "本溪", "210500"),
+    city("0415", "辽宁", "丹东", "210600"),
+    city("0416", "辽宁", "锦州", "210700"),
+    city("0417", "辽宁", "营口", "210800"),
+    city("0418", "辽宁", "阜新", "210900"),
+    city("0419", "辽宁", "辽阳", "211000"),
+    city("0421", "辽宁", "朝阳", "211300"),
+    city("0427", "辽宁", "盘锦", "211100"),
+    city("0429", "辽宁", "葫芦岛", "211400"),
+    city("0431", "吉林", "长春", "220100"),
+    city("0432", "吉林", "吉林", "220200"),
+    city("0433", "吉林", "延边", "222400"),
+    city("0434", "吉林", "四平", "220300"),
+    city("0435", "吉林", "通化", "220500"),
+    city("0436", "吉林", "白城", "220800"),
+    city("0437", "吉林", "辽源", "220400"),
+    city("0438", "吉林", "松原", "220700"),
+    city("0439", "吉林", "白山", "220600"),
+    city("0451", "黑龙江", "哈尔滨", "230100"),
+    city("0452", "黑龙江", "齐齐哈尔", "230200"),
+    city("0453", "黑龙江", "牡丹江", "231000"),
+    city("0454", "黑龙江", "佳木斯", "230800"),
+    city("0455", "黑龙江", "绥化", "231200"),
+    city("0456", "黑龙江", "黑河", "231100"),
+    city("0457", "黑龙江", "大兴安岭", "232700"),
+    city("0458", "黑龙江", "伊春", "230700"),
+    city("0459", "黑龙江", "大庆", "230600"),
+    city("0464", "黑龙江", "七台河", "230900"),
+    city("0467", "黑龙江", "鸡西", "230300"),
+    city("0468", "黑龙江", "鹤岗", "230400"),
+    city("0469", "黑龙江", "双鸭山", "230500"),
+    city("0470", "内蒙古", "呼伦贝尔", "150700"),
+    city("0471", "内蒙古", "呼和浩特", "150100"),
+    city("0472", "内蒙古", "包头", "150200"),
+    city("0473", "内蒙古", "乌海", "150300"),
+    city("0474", "内蒙古", "乌兰察布", "150900"),
+    city("0475", "内蒙古", "通辽", "150500"),
+    city("0476", "内蒙古", "赤峰", "150400"),
+    city("0477", "内蒙古", "鄂尔多斯", "150600"),
+    city("0478", "内蒙古", "巴彦淖尔", "150800"),
+    city("0479", "内蒙古", "锡林郭勒", "152500"),
+    city("0482", "内蒙古", "兴安", "152200"),
+    city("0483", "内蒙古", "阿拉善", "152900"),
+    city("0510", "江苏", "无锡", "320200"),
+    city("0511", "江苏", "镇江", "321100"),
+    city("0512", "江苏", "苏州", "320500"),
+    city("0513", "江苏", "南通", "320600"),
+    city("0514", "江苏", "扬州", "321000"),
+    city("0515", "江苏", "盐城", "320900"),
+    city("0516", "江苏", "徐州", "320300"),
+    city("0517", "江苏", "淮安", "320800"),
+    city("0518", "江苏", "连云港", "320700"),
+    city("0519", "江苏", "常州", "320400"),
+    city("0523", "江苏", "泰州", "321200"),
+    city("0527", "江苏", "宿迁", "321300"),
+    city("0530", "山东", "菏泽", "371700"),
+    city("0531", "山东", "济南", "370100"),
+    city("0532", "山东", "青岛", "370200"),
+    city("0533", "山东", "淄博", "370300"),
+    city("0534", "山东", "德州", "371400"),
+    city("0535", "山东", "烟台", "370600"),
+    city("0536", "山东", "潍坊", "370700"),
+    city("0537", "山东", "济宁", "370800"),
+    city("0538", "山东", "泰安", "370900"),
+    city("0539", "山东", "临沂", "371300"),
+    city("0543", "山东", "滨州", "371600"),
+    city("0546", "山东", "东营", "370500"),
+    city("0550", "安徽", "滁州", "341100"),
+    city("0551", "安徽", "合肥", "340100"),
+    city("0552", "安徽", "蚌埠", "340300"),
+    city("0553", "安徽", "芜湖", "340200"),
+    city("0554", "安徽", "淮南", "340400"),
+    city("0555", "安徽", "马鞍山", "340500"),
+    city("0556", "安徽", "安庆", "340800"),
+    city("0557", "安徽", "宿州", "341300"),
+    city("0558", "安徽", "阜阳", "341200"),
+    city("0559", "安徽", "黄山", "341000"),
+    city("0561", "安徽", "淮北", "340600"),
+    city("0562", "安徽", "铜陵", "340700"),
+    city("0563", "安徽", "宣城", "341800"),
+    city("0564", "安徽", "六安", "341500"),
+    city("0566", "安徽", "池州", "341700"),
+    city("0570", "浙江", "衢州", "330800"),
+    city("0571", "浙江", "杭州", "330100"),
+    city("0572", "浙江", "湖州", "330500"),
+    city("0573", "浙江", "嘉兴", "330400"),
+    city("0574", "浙江", "宁波", "330200"),
+    city("0575", "浙江", "绍兴", "330600"),
+    city("0576", "浙江", "台州", "331000"),
+    city("0577", "浙江", "温州", "330300"),
+    city("0578", "浙江", "丽水", "331100"),
+    city("0579", "浙江", "金华", "330700"),
+    city("0580", "浙江", "舟山", "330900"),
+    city("0591", "福建", "福州", "350100"),
+    city("0592", "福建", "厦门", "350200"),
+    city("0593", "福建", "宁德", "350900"),
+    city("0594", "福建", "莆田", "350300"),
+    city("0595", "福建", "泉州", "350500"),
+    city("0596", "福建", "漳州", "350600"),
+    city("0597", "福建", "龙岩", "350800"),
+    city("0598", "福建", "三明", "350400"),
+    city("0599", "福建", "南平", "350700"),
+    city("0631", "山东", "威海", "371000"),
+    city("0632", "山东", "枣庄", "370400"),
+    city("0633", "山东", "日照", "371100"),
+    city("0634", "山东", "莱芜", "370116"),
+    city("0635", "山东", "聊城", "371500"),
+    city("0660", "广东", "汕尾", "441500"),
+    city("0662", "广东", "阳江", "441700"),
+    city("0663", "广东", "揭阳", "445200"),
+    city("0668", "广东", "茂名", "440900"),
+    city("0691", "云南", "西双版纳", "532800"),
+    city("0692", "云南", "德宏", "533100"),
+    city("0701", "江西", "鹰潭", "360600"),
+    city("0710", "湖北", "襄阳", "420600"),
+    city("0711", "湖北", "鄂州", "420700"),
+    city("0712", "湖北", "孝感", "420900"),
+    city("0713", "湖北", "黄冈", "421100"),
+    city("0714", "湖北", "黄石", "420200"),
+    city("0715", "湖北", "咸宁", "421200"),
+    city("0716", "湖北", "荆州", "421000"),
+    city("0717", "湖北", "宜昌", "420500"),
+    city("0718", "湖北", "恩施", "422800"),
+    city("0719", "湖北", "十堰", "420300"),
+    city("0722", "湖北", "随州", "421300"),
+    city("0724", "湖北", "荆门", "420800"),
+    city("0728", "湖北", "仙桃", "429004"),
+    city("0730", "湖南", "岳阳", "430600"),
+    city("0731", "湖南", "长沙", "430100"),
+    city("0734", "湖南", "衡阳", "430400"),
+    city("0735", "湖南", "郴州", "431000"),
+    city("0736", "湖南", "常德", "430700"),
+    city("0737", "湖南", "益阳", "430900"),
+    city("0738", "湖南", "娄底", "431300"),
+    city("0739", "湖南", "邵阳", "430500"),
+    city("0743", "湖南", "湘西", "433100"),
+    city("0744", "湖南", "张家界", "430800"),
+    city("0745", "湖南", "怀化", "431200"),
+    city("0746", "湖南", "永州", "431100"),
+    city("0750", "广东", "江门", "440700"),
+    city("0751", "广东", "韶关", "440200"),
+    city("0752", "广东", "惠州", "441300"),
+    city("0753", "广东", "梅州", "441400"),
+    city("0754", "广东", "汕头", "440500"),
+    city("0755", "广东", "深圳", "440300"),
+    city("0756", "广东", "珠海", "440400"),
+    city("0757", "广东", "佛山", "440600"),
+    city("0758", "广东", "肇庆", "441200"),
+    city("0759", "广东", "湛江", "440800"),
+    city("0760", "广东", "中山", "442000"),
+    city("0762", "广东", "河源", "441600"),
+    city("0763", "广东", "清远", "441800"),
+    city("0766", "广东", "云浮", "445300"),
+    city("0768", "广东", "潮州", "445100"),
+    city("0769", "广东", "东莞", "441900"),
+    city("0770", "广西", "防城港", "450600"),
+    city("0771", "广西", "南宁", "450100"),
+    city("0772", "广西", "柳州", "450200"),
+    city("0773", "广西", "桂林", "450300"),
+    city("0774", "广西", "梧州", "450400"),
+    city("0775", "广西", "玉林", "450900"),
+    city("0776", "广西", "百色", "451000"),
+    city("0777", "广西", "钦州", "450700"),
+    city("0778", "广西", "河池", "451200"),
+    city("0779", "广西", "北海", "450500"),
+    city("0790", "江西", "新余", "360500"),
+    city("0791", "江西", "南昌", "360100"),
+    city("0792", "江西", "九江", "360400"),
+    city("0793", "江西", "上饶", "361100"),
+    city("0794", "江西", "抚州", "361000"),
+    city("0795", "江西", "宜春", "360900"),
+    city("0796", "江西", "吉安", "360800"),
+    city("0797", "江西", "赣州", "360700"),
+    city("0798", "江西", "景德镇", "360200"),
+    city("0799", "江西", "萍乡", "360300"),
+    city("0812", "四川", "攀枝花", "510400"),
+    city("0813", "四川", "自贡", "510300"),
+    city("0816", "四川", "绵阳", "510700"),
+    city("0817", "四川", "南充", "511300"),
+    city("0818", "四川", "达州", "511700"),
+    city("0825", "四川", "遂宁", "510900"),
+    city("0826", "四川", "广安", "511600"),
+    city("0827", "四川", "巴中", "511900"),
+    city("0830", "四川", "泸州", "510500"),
+    city("0831", "四川", "宜宾", "511500"),
+    city("0832", "四川", "内江", "511000"),
+    city("0833", "四川", "乐山", "511100"),
+    city("0834", "四川", "凉山", "513400"),
+    city("0835", "四川", "雅安", "511800"),
+    city("0836", "四川", "甘孜", "513300"),
+    city("0837", "四川", "阿坝", "513200"),
+    city("0838", "四川", "德阳", "510600"),
+    city("0839", "四川", "广元", "510800"),
+    city("0851", "贵州", "贵阳", "520100"),
+    city("0852", "贵州", "遵义", "520300"),
+    city("0853", "贵州", "安顺", "520400"),
+    city("0854", "贵州", "黔南", "522700"),
+    city("0855", "贵州", "黔东南", "522600"),
+    city("0856", "贵州", "铜仁", "520600"),
+    city("0857", "贵州", "毕节", "520500"),
+    city("0858", "贵州", "六盘水", "520200"),
+    city("0859", "贵州", "黔西南", "522300"),
+    city("0870", "云南", "昭通", "530600"),
+    city("0871", "云南", "昆明", "530100"),
+    city("0872", "云南", "大理", "532900"),
+    city("0873", "云南", "红河", "532500"),
+    city("0874", "云南", "曲靖", "530300"),
+    city("0875", "云南", "保山", "530500"),
+    city("0876", "云南", "文山", "532600"),
+    city("0877", "云南", "玉溪", "530400"),
+    city("0878", "云南", "楚雄", "532300"),
+    city("0879", "云南", "普洱", "530800"),
+    city("0883", "云南", "临沧", "530900"),
+    city("0886", "云南", "怒江", "533300"),
+    city("0887", "云南", "迪庆", "533400"),
+    city("0888", "云南", "丽江", "530700"),
+    city("0891", "西藏", "拉萨", "540100"),
+    city("0892", "西藏", "日喀则", "540200"),
+    city("0893", "西藏", "山南", "540500"),
+    city("0894", "西藏", "林芝", "540400"),
+    city("0895", "西藏", "昌都", "540300"),
+    city("0896", "西藏", "那曲", "540600"),
+    city("0897", "西藏", "阿里", "542500"),
+    city("0898", "海南", "海南", "460000"),
+    city("0901", "新疆", "塔城", "654200"),
+    city("0902", "新疆", "哈密", "650500"),
+    city("0903", "新疆", "和田", "653200"),
+    city("0906", "新疆", "阿勒泰", "654300"),
+    city("0908", "新疆", "克孜勒苏", "653000"),
+    city("0909", "新疆", "博尔塔拉", "652700"),
+    city("0911", "陕西", "延安", "610600"),
+    city("0912", "陕西", "榆林", "610800"),
+    city("0913", "陕西", "渭南", "610500"),
+    city("0914", "陕西", "商洛", "611000"),
+    city("0915", "陕西", "安康", "610900"),
+    city("0916", "陕西", "汉中", "610700"),
+    city("0917", "陕西", "宝鸡", "610300"),
+    city("0919", "陕西", "铜川", "610200"),
+    city("0930", "甘肃", "临夏", "622900"),
+    city("0931", "甘肃", "兰州", "620100"),
+    city("0932", "甘肃", "定西", "621100"),
+    city("0933", "甘肃", "平凉", "620800"),
+    city("0934", "甘肃", "庆阳", "621000"),
+    city("0935", "甘肃", "武威", "620600"),
+    city("0936", "甘肃", "张掖", "620700"),
+    city("0937", "甘肃", "酒泉", "620900"),
+    city("0938", "甘肃", "天水", "620500"),
+    city("0939", "甘肃", "陇南", "621200"),
+    city("0941", "甘肃", "甘南", "623000"),
+    city("0943", "甘肃", "白银", "620400"),
+    city("0951", "宁夏", "银川", "640100"),
+    city("0952", "宁夏", "石嘴山", "640200"),
+    city("0953", "宁夏", "吴忠", "640300"),
+    city("0954", "宁夏", "固原", "640400"),
+    city("0955", "宁夏", "中卫", "640500"),
+    city("0970", "青海", "海北", "632200"),
+    city("0971", "青海", "西宁", "630100"),
+    city("0972", "青海", "海东", "630200"),
+    city("0973", "青海", "黄南", "632300"),
+    city("0974", "青海", "海南州", "632500"),
+    city("0975", "青海", "果洛", "632600"),
+    city("0976", "青海", "玉树", "632700"),
+    city("0977", "青海", "海西", "632800"),
+    city("0990", "新疆", "克拉玛依", "650200"),
+    city("0991", "新疆", "乌鲁木齐", "650100"),
+    city("0992", "新疆", "奎屯", "654003"),
+    city("0993", "新疆", "石河子", "659001"),
+    city("0994", "新疆", "昌吉", "652300"),
+    city("0995", "新疆", "吐鲁番", "650400"),
+    city("0996", "新疆", "巴音郭楞", "652800"),
+    city("0997", "新疆", "阿克苏", "652900"),
+    city("0998", "新疆", "喀什", "653100"),
+    city("0999", "新疆", "伊犁", "654000"),
+];
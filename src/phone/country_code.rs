@@ -0,0 +1,286 @@
+//! 国际电话区号
+
+/// 国际电话区号对应的国家或地区
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Country {
+    /// 国际电话区号，如 `852`
+    pub calling_code: &'static str,
+    /// ISO 3166-1 alpha-2 代码，如 `HK`，国际免费电话等非地理区号为 `None`
+    pub iso: Option<&'static str>,
+    /// 中文名称，如 `中国香港`
+    pub name_zh: &'static str,
+    /// 英文名称，如 `Hong Kong`
+    pub name_en: &'static str,
+}
+
+const fn country(
+    calling_code: &'static str,
+    iso: Option<&'static str>,
+    name_zh: &'static str,
+    name_en: &'static str,
+) -> Country {
+    Country {
+        calling_code,
+        iso,
+        name_zh,
+        name_en,
+    }
+}
+
+/// ITU-T E.164 分配的国际电话区号，按区号的字典序排列以便二分查找
+///
+/// 区号之间互不为前缀，因此按 1~3 位依次查找即可得到唯一的匹配；
+/// 多个国家共用同一区号时（如 `1`、`7`），仅记录其中的主要国家
+pub(crate) static COUNTRY_CODES: [Country; 216] = [
+    country("1", Some("US"), "北美", "United States"),
+    country("20", Some("EG"), "埃及", "Egypt"),
+    country("211", Some("SS"), "南苏丹", "South Sudan"),
+    country("212", Some("MA"), "摩洛哥", "Morocco"),
+    country("213", Some("DZ"), "阿尔及利亚", "Algeria"),
+    country("216", Some("TN"), "突尼斯", "Tunisia"),
+    country("218", Some("LY"), "利比亚", "Libya"),
+    country("220", Some("GM"), "冈比亚", "Gambia"),
+    country("221", Some("SN"), "塞内加尔", "Senegal"),
+    country("222", Some("MR"), "毛里塔尼亚", "Mauritania"),
+    country("223", Some("ML"), "马里", "Mali"),
+    country("224", Some("GN"), "几内亚", "Guinea"),
+    country("225", Some("CI"), "科特迪瓦", "Côte d'Ivoire"),
+    country("226", Some("BF"), "布基纳法索", "Burkina Faso"),
+    country("227", Some("NE"), "尼日尔", "Niger"),
+    country("228", Some("TG"), "多哥", "Togo"),
+    country("229", Some("BJ"), "贝宁", "Benin"),
+    country("230", Some("MU"), "毛里求斯", "Mauritius"),
+    country("231", Some("LR"), "利比里亚", "Liberia"),
+    country("232", Some("SL"), "塞拉利昂", "Sierra Leone"),
+    country("233", Some("GH"), "加纳", "Ghana"),
+    country("234", Some("NG"), "尼日利亚", "Nigeria"),
+    country("235", Some("TD"), "乍得", "Chad"),
+    country("236", Some("CF"), "中非", "Central African Republic"),
+    country("237", Some("CM"), "喀麦隆", "Cameroon"),
+    country("238", Some("CV"), "佛得角", "Cape Verde"),
+    country(
+        "239",
+        Some("ST"),
+        "圣多美和普林西比",
+        "São Tomé and Príncipe",
+    ),
+    country("240", Some("GQ"), "赤道几内亚", "Equatorial Guinea"),
+    country("241", Some("GA"), "加蓬", "Gabon"),
+    country("242", Some("CG"), "刚果（布）", "Republic of the Congo"),
+    country("243", Some("CD"), "刚果（金）", "DR Congo"),
+    country("244", Some("AO"), "安哥拉", "Angola"),
+    country("245", Some("GW"), "几内亚比绍", "Guinea-Bissau"),
+    country("246", Some("IO"), "迪戈加西亚", "Diego Garcia"),
+    country("247", Some("SH"), "阿森松岛", "Ascension Island"),
+    country("248", Some("SC"), "塞舌尔", "Seychelles"),
+    country("249", Some("SD"), "苏丹", "Sudan"),
+    country("250", Some("RW"), "卢旺达", "Rwanda"),
+    country("251", Some("ET"), "埃塞俄比亚", "Ethiopia"),
+    country("252", Some("SO"), "索马里", "Somalia"),
+    country("253", Some("DJ"), "吉布提", "Djibouti"),
+    country("254", Some("KE"), "肯尼亚", "Kenya"),
+    country("255", Some("TZ"), "坦桑尼亚", "Tanzania"),
+    country("256", Some("UG"), "乌干达", "Uganda"),
+    country("257", Some("BI"), "布隆迪", "Burundi"),
+    country("258", Some("MZ"), "莫桑比克", "Mozambique"),
+    country("260", Some("ZM"), "赞比亚", "Zambia"),
+    country("261", Some("MG"), "马达加斯加", "Madagascar"),
+    country("262", Some("RE"), "留尼汪", "Réunion"),
+    country("263", Some("ZW"), "津巴布韦", "Zimbabwe"),
+    country("264", Some("NA"), "纳米比亚", "Namibia"),
+    country("265", Some("MW"), "马拉维", "Malawi"),
+    country("266", Some("LS"), "莱索托", "Lesotho"),
+    country("267", Some("BW"), "博茨瓦纳", "Botswana"),
+    country("268", Some("SZ"), "斯威士兰", "Eswatini"),
+    country("269", Some("KM"), "科摩罗", "Comoros"),
+    country("27", Some("ZA"), "南非", "South Africa"),
+    country("290", Some("SH"), "圣赫勒拿", "Saint Helena"),
+    country("291", Some("ER"), "厄立特里亚", "Eritrea"),
+    country("297", Some("AW"), "阿鲁巴", "Aruba"),
+    country("298", Some("FO"), "法罗群岛", "Faroe Islands"),
+    country("299", Some("GL"), "格陵兰", "Greenland"),
+    country("30", Some("GR"), "希腊", "Greece"),
+    country("31", Some("NL"), "荷兰", "Netherlands"),
+    country("32", Some("BE"), "比利时", "Belgium"),
+    country("33", Some("FR"), "法国", "France"),
+    country("34", Some("ES"), "西班牙", "Spain"),
+    country("350", Some("GI"), "直布罗陀", "Gibraltar"),
+    country("351", Some("PT"), "葡萄牙", "Portugal"),
+    country("352", Some("LU"), "卢森堡", "Luxembourg"),
+    country("353", Some("IE"), "爱尔兰", "Ireland"),
+    country("354", Some("IS"), "冰岛", "Iceland"),
+    country("355", Some("AL"), "阿尔巴尼亚", "Albania"),
+    country("356", Some("MT"), "马耳他", "Malta"),
+    country("357", Some("CY"), "塞浦路斯", "Cyprus"),
+    country("358", Some("FI"), "芬兰", "Finland"),
+    country("359", Some("BG"), "保加利亚", "Bulgaria"),
+    country("36", Some("HU"), "匈牙利", "Hungary"),
+    country("370", Some("LT"), "立陶宛", "Lithuania"),
+    country("371", Some("LV"), "拉脱维亚", "Latvia"),
+    country("372", Some("EE"), "爱沙尼亚", "Estonia"),
+    country("373", Some("MD"), "摩尔多瓦", "Moldova"),
+    country("374", Some("AM"), "亚美尼亚", "Armenia"),
+    country("375", Some("BY"), "白俄罗斯", "Belarus"),
+    country("376", Some("AD"), "安道尔", "Andorra"),
+    country("377", Some("MC"), "摩纳哥", "Monaco"),
+    country("378", Some("SM"), "圣马力诺", "San Marino"),
+    country("379", Some("VA"), "梵蒂冈", "Vatican City"),
+    country("380", Some("UA"), "乌克兰", "Ukraine"),
+    country("381", Some("RS"), "塞尔维亚", "Serbia"),
+    country("382", Some("ME"), "黑山", "Montenegro"),
+    country("383", Some("XK"), "科索沃", "Kosovo"),
+    country("385", Some("HR"), "克罗地亚", "Croatia"),
+    country("386", Some("SI"), "斯洛文尼亚", "Slovenia"),
+    country("387", Some("BA"), "波黑", "Bosnia and Herzegovina"),
+    country("389", Some("MK"), "北马其顿", "North Macedonia"),
+    country("39", Some("IT"), "意大利", "Italy"),
+    country("40", Some("RO"), "罗马尼亚", "Romania"),
+    country("41", Some("CH"), "瑞士", "Switzerland"),
+    country("420", Some("CZ"), "捷克", "Czech Republic"),
+    country("421", Some("SK"), "斯洛伐克", "Slovakia"),
+    country("423", Some("LI"), "列支敦士登", "Liechtenstein"),
+    country("43", Some("AT"), "奥地利", "Austria"),
+    country("44", Some("GB"), "英国", "United Kingdom"),
+    country("45", Some("DK"), "丹麦", "Denmark"),
+    country("46", Some("SE"), "瑞典", "Sweden"),
+    country("47", Some("NO"), "挪威", "Norway"),
+    country("48", Some("PL"), "波兰", "Poland"),
+    country("49", Some("DE"), "德国", "Germany"),
+    country("500", Some("FK"), "福克兰群岛", "Falkland Islands"),
+    country("501", Some("BZ"), "伯利兹", "Belize"),
+    country("502", Some("GT"), "危地马拉", "Guatemala"),
+    country("503", Some("SV"), "萨尔瓦多", "El Salvador"),
+    country("504", Some("HN"), "洪都拉斯", "Honduras"),
+    country("505", Some("NI"), "尼加拉瓜", "Nicaragua"),
+    country("506", Some("CR"), "哥斯达黎加", "Costa Rica"),
+    country("507", Some("PA"), "巴拿马", "Panama"),
+    country(
+        "508",
+        Some("PM"),
+        "圣皮埃尔和密克隆",
+        "Saint Pierre and Miquelon",
+    ),
+    country("509", Some("HT"), "海地", "Haiti"),
+    country("51", Some("PE"), "秘鲁", "Peru"),
+    country("52", Some("MX"), "墨西哥", "Mexico"),
+    country("53", Some("CU"), "古巴", "Cuba"),
+    country("54", Some("AR"), "阿根廷", "Argentina"),
+    country("55", Some("BR"), "巴西", "Brazil"),
+    country("56", Some("CL"), "智利", "Chile"),
+    country("57", Some("CO"), "哥伦比亚", "Colombia"),
+    country("58", Some("VE"), "委内瑞拉", "Venezuela"),
+    country("590", Some("GP"), "瓜德罗普", "Guadeloupe"),
+    country("591", Some("BO"), "玻利维亚", "Bolivia"),
+    country("592", Some("GY"), "圭亚那", "Guyana"),
+    country("593", Some("EC"), "厄瓜多尔", "Ecuador"),
+    country("594", Some("GF"), "法属圭亚那", "French Guiana"),
+    country("595", Some("PY"), "巴拉圭", "Paraguay"),
+    country("596", Some("MQ"), "马提尼克", "Martinique"),
+    country("597", Some("SR"), "苏里南", "Suriname"),
+    country("598", Some("UY"), "乌拉圭", "Uruguay"),
+    country("599", Some("CW"), "荷属加勒比", "Caribbean Netherlands"),
+    country("60", Some("MY"), "马来西亚", "Malaysia"),
+    country("61", Some("AU"), "澳大利亚", "Australia"),
+    country("62", Some("ID"), "印度尼西亚", "Indonesia"),
+    country("63", Some("PH"), "菲律宾", "Philippines"),
+    country("64", Some("NZ"), "新西兰", "New Zealand"),
+    country("65", Some("SG"), "新加坡", "Singapore"),
+    country("66", Some("TH"), "泰国", "Thailand"),
+    country("670", Some("TL"), "东帝汶", "Timor-Leste"),
+    country(
+        "672",
+        Some("NF"),
+        "澳大利亚海外领地",
+        "Australian External Territories",
+    ),
+    country("673", Some("BN"), "文莱", "Brunei"),
+    country("674", Some("NR"), "瑙鲁", "Nauru"),
+    country("675", Some("PG"), "巴布亚新几内亚", "Papua New Guinea"),
+    country("676", Some("TO"), "汤加", "Tonga"),
+    country("677", Some("SB"), "所罗门群岛", "Solomon Islands"),
+    country("678", Some("VU"), "瓦努阿图", "Vanuatu"),
+    country("679", Some("FJ"), "斐济", "Fiji"),
+    country("680", Some("PW"), "帕劳", "Palau"),
+    country("681", Some("WF"), "瓦利斯和富图纳", "Wallis and Futuna"),
+    country("682", Some("CK"), "库克群岛", "Cook Islands"),
+    country("683", Some("NU"), "纽埃", "Niue"),
+    country("685", Some("WS"), "萨摩亚", "Samoa"),
+    country("686", Some("KI"), "基里巴斯", "Kiribati"),
+    country("687", Some("NC"), "新喀里多尼亚", "New Caledonia"),
+    country("688", Some("TV"), "图瓦卢", "Tuvalu"),
+    country("689", Some("PF"), "法属波利尼西亚", "French Polynesia"),
+    country("690", Some("TK"), "托克劳", "Tokelau"),
+    country("691", Some("FM"), "密克罗尼西亚", "Micronesia"),
+    country("692", Some("MH"), "马绍尔群岛", "Marshall Islands"),
+    country("7", Some("RU"), "俄罗斯、哈萨克斯坦", "Russia"),
+    country("800", None, "国际免费电话", "International Freephone"),
+    country(
+        "808",
+        None,
+        "国际共享费用服务",
+        "International Shared Cost Service",
+    ),
+    country("81", Some("JP"), "日本", "Japan"),
+    country("82", Some("KR"), "韩国", "South Korea"),
+    country("84", Some("VN"), "越南", "Vietnam"),
+    country("850", Some("KP"), "朝鲜", "North Korea"),
+    country("852", Some("HK"), "中国香港", "Hong Kong"),
+    country("853", Some("MO"), "中国澳门", "Macau"),
+    country("855", Some("KH"), "柬埔寨", "Cambodia"),
+    country("856", Some("LA"), "老挝", "Laos"),
+    country("86", Some("CN"), "中国", "China"),
+    country("870", None, "国际海事卫星", "Inmarsat"),
+    country(
+        "878",
+        None,
+        "通用个人通信",
+        "Universal Personal Telecommunications",
+    ),
+    country("880", Some("BD"), "孟加拉国", "Bangladesh"),
+    country(
+        "881",
+        None,
+        "全球移动卫星系统",
+        "Global Mobile Satellite System",
+    ),
+    country("882", None, "国际网络", "International Networks"),
+    country("883", None, "国际网络", "International Networks"),
+    country("886", Some("TW"), "中国台湾", "Taiwan"),
+    country("888", None, "人道主义服务", "Humanitarian Affairs"),
+    country("90", Some("TR"), "土耳其", "Turkey"),
+    country("91", Some("IN"), "印度", "India"),
+    country("92", Some("PK"), "巴基斯坦", "Pakistan"),
+    country("93", Some("AF"), "阿富汗", "Afghanistan"),
+    country("94", Some("LK"), "斯里兰卡", "Sri Lanka"),
+    country("95", Some("MM"), "缅甸", "Myanmar"),
+    country("960", Some("MV"), "马尔代夫", "Maldives"),
+    country("961", Some("LB"), "黎巴嫩", "Lebanon"),
+    country("962", Some("JO"), "约旦", "Jordan"),
+    country("963", Some("SY"), "叙利亚", "Syria"),
+    country("964", Some("IQ"), "伊拉克", "Iraq"),
+    country("965", Some("KW"), "科威特", "Kuwait"),
+    country("966", Some("SA"), "沙特阿拉伯", "Saudi Arabia"),
+    country("967", Some("YE"), "也门", "Yemen"),
+    country("968", Some("OM"), "阿曼", "Oman"),
+    country("970", Some("PS"), "巴勒斯坦", "Palestine"),
+    country("971", Some("AE"), "阿联酋", "United Arab Emirates"),
+    country("972", Some("IL"), "以色列", "Israel"),
+    country("973", Some("BH"), "巴林", "Bahrain"),
+    country("974", Some("QA"), "卡塔尔", "Qatar"),
+    country("975", Some("BT"), "不丹", "Bhutan"),
+    country("976", Some("MN"), "蒙古", "Mongolia"),
+    country("977", Some("NP"), "尼泊尔", "Nepal"),
+    country(
+        "979",
+        None,
+        "国际附加费率服务",
+        "International Premium Rate Service",
+    ),
+    country("98", Some("IR"), "伊朗", "Iran"),
+    country("992", Some("TJ"), "塔吉克斯坦", "Tajikistan"),
+    country("993", Some("TM"), "土库曼斯坦", "Turkmenistan"),
+    country("994", Some("AZ"), "阿塞拜疆", "Azerbaijan"),
+    country("995", Some("GE"), "格鲁吉亚", "Georgia"),
+    country("996", Some("KG"), "吉尔吉斯斯坦", "Kyrgyzstan"),
+    country("998", Some("UZ"), "乌兹别克斯坦", "Uzbekistan"),
+];
@@ -0,0 +1,67 @@
+//! 电话号码相关的错误
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// 电话号码相关的错误
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PhoneError {
+    /// 号码为空
+    Empty,
+    /// 号码超过允许的最大长度
+    TooLong(usize),
+    /// 不是正常的电话号码
+    Invalid(String),
+}
+
+impl Display for PhoneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PhoneError::Empty => f.write_str("号码为空"),
+            PhoneError::TooLong(len) => write!(f, "号码长度 {} 超过允许的最大长度", len),
+            PhoneError::Invalid(number) => write!(f, "{} 不是正常的电话号码", number),
+        }
+    }
+}
+
+impl Error for PhoneError {}
+
+/// 号码校验失败的原因
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// 号码为空
+    Empty,
+    /// 号码超过允许的最大长度
+    TooLong(usize),
+    /// 号码中含有非法字符
+    IllegalCharacter(char),
+    /// 号码长度不正确
+    BadLength(usize),
+    /// 手机号码的号段未分配
+    UnallocatedPrefix(String),
+    /// 固定电话的区号不存在
+    UnknownAreaCode(String),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::Empty => f.write_str("号码为空"),
+            ValidationError::TooLong(len) => {
+                write!(f, "号码长度 {} 超过允许的最大长度", len)
+            }
+            ValidationError::IllegalCharacter(c) => write!(f, "号码中含有非法字符 {}", c),
+            ValidationError::BadLength(len) => write!(f, "号码长度 {} 不正确", len),
+            ValidationError::UnallocatedPrefix(prefix) => {
+                write!(f, "手机号段 {} 未分配", prefix)
+            }
+            ValidationError::UnknownAreaCode(area_code) => {
+                write!(f, "区号 {} 不存在", area_code)
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {}
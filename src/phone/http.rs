@@ -0,0 +1,171 @@
+//! 基于 HTTP 接口的号段数据源
+
+use std::time::Duration;
+
+use super::{AsyncSegmentProvider, EmbeddedProvider, SegmentInfo, SegmentProvider};
+
+/// 默认的请求超时时间
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 通过 HTTP 接口异步查询号段信息的数据源
+///
+/// `endpoint` 中的 `{segment}` 会被替换为号段，接口需返回 [`SegmentInfo`] 格式的 JSON，
+/// 如 `{"vendor": "10086 移动", "province": "北京", "city": "北京"}`
+///
+/// 请求失败、超时或返回非 2xx 状态码时，回退到内置的 [`EmbeddedProvider`]，
+/// 也可以通过 [`HttpProvider::fallback`] 指定其它数据源
+///
+/// 实现了 [`AsyncSegmentProvider`]，可以通过 [`lookup_with_async`](super::lookup_with_async) 使用
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use yansongda_utils::phone::{lookup_with_async, HttpProvider};
+///
+/// # async fn run() {
+/// let provider = HttpProvider::new("https://example.com/segments/{segment}")
+///     .timeout(Duration::from_millis(500));
+///
+/// let info = lookup_with_async(&provider, "13800138000").await;
+/// # }
+/// ```
+pub struct HttpProvider {
+    client: reqwest::Client,
+    endpoint: String,
+    timeout: Duration,
+    fallback: Box<dyn SegmentProvider + Send + Sync>,
+}
+
+impl HttpProvider {
+    /// 使用指定的接口地址创建数据源，默认超时时间为 3 秒
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        HttpProvider {
+            client: reqwest::Client::new(),
+            endpoint: endpoint.into(),
+            timeout: DEFAULT_TIMEOUT,
+            fallback: Box::new(EmbeddedProvider),
+        }
+    }
+
+    /// 设置请求超时时间
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置请求失败时使用的数据源
+    pub fn fallback<P: SegmentProvider + Send + Sync + 'static>(mut self, provider: P) -> Self {
+        self.fallback = Box::new(provider);
+        self
+    }
+
+    /// 使用自定义的 [`reqwest::Client`]，如需配置代理、请求头等
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    async fn fetch(&self, segment: &str) -> reqwest::Result<SegmentInfo> {
+        self.client
+            .get(self.endpoint.replace("{segment}", segment))
+            .timeout(self.timeout)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+}
+
+impl AsyncSegmentProvider for HttpProvider {
+    async fn lookup_async(&self, segment: &str) -> Option<SegmentInfo> {
+        match self.fetch(segment).await {
+            Ok(info) => Some(info),
+            Err(_) => self.fallback.lookup(segment),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+    use crate::phone::{lookup_with_async, MobileVendor};
+
+    fn serve_once(status: &str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let status = status.to_string();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf).unwrap();
+            let response = format!(
+                "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        format!("http://{}/segments/{{segment}}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_http_provider() {
+        let endpoint = serve_once(
+            "200 OK",
+            r#"{"vendor": "10086 移动", "province": "北京", "city": "北京"}"#,
+        );
+        let provider = HttpProvider::new(endpoint);
+
+        assert_eq!(
+            Some(SegmentInfo {
+                vendor: MobileVendor::Mobile,
+                province: Some(String::from("北京")),
+                city: Some(String::from("北京")),
+            }),
+            lookup_with_async(&provider, "+8613800138000").await
+        );
+        assert_eq!(None, lookup_with_async(&provider, "01012345678").await);
+    }
+
+    #[tokio::test]
+    async fn test_http_provider_fallback() {
+        let embedded = Some(SegmentInfo {
+            vendor: MobileVendor::Unicom,
+            province: None,
+            city: None,
+        });
+
+        let provider = HttpProvider::new(serve_once("404 Not Found", "{}"));
+        assert_eq!(embedded, provider.lookup_async("1860013").await);
+
+        let provider = HttpProvider::new(serve_once("200 OK", "not json"));
+        assert_eq!(embedded, provider.lookup_async("1860013").await);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/{{segment}}", listener.local_addr().unwrap());
+        let provider = HttpProvider::new(endpoint).timeout(Duration::from_millis(100));
+        assert_eq!(embedded, provider.lookup_async("1860013").await);
+
+        struct Empty;
+
+        impl SegmentProvider for Empty {
+            fn lookup(&self, _segment: &str) -> Option<SegmentInfo> {
+                None
+            }
+        }
+
+        let provider =
+            HttpProvider::new(serve_once("500 Internal Server Error", "{}")).fallback(Empty);
+        assert_eq!(None, provider.lookup_async("1860013").await);
+    }
+}
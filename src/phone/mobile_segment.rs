@@ -0,0 +1,82 @@
+//! 中国大陆手机号段与运营商的对应关系
+
+use super::MobileVendor;
+
+/// 手机号段及其所属运营商，按字典序排列以便二分查找
+///
+/// 号段为 3 位，或者同一 3 位号段分属不同运营商时为 4 位；
+/// 虚拟运营商号段归属于其基础运营商
+pub(crate) const MOBILE_SEGMENTS: [(&str, MobileVendor); 63] = [
+    ("130", MobileVendor::Unicom),
+    ("131", MobileVendor::Unicom),
+    ("132", MobileVendor::Unicom),
+    ("133", MobileVendor::Telecom),
+    ("134", MobileVendor::Mobile),
+    ("1349", MobileVendor::Telecom), // 卫星电话
+    ("135", MobileVendor::Mobile),
+    ("136", MobileVendor::Mobile),
+    ("137", MobileVendor::Mobile),
+    ("138", MobileVendor::Mobile),
+    ("139", MobileVendor::Mobile),
+    ("145", MobileVendor::Unicom), // 上网卡
+    ("147", MobileVendor::Mobile), // 上网卡
+    ("149", MobileVendor::Telecom),
+    ("150", MobileVendor::Mobile),
+    ("151", MobileVendor::Mobile),
+    ("152", MobileVendor::Mobile),
+    ("153", MobileVendor::Telecom),
+    ("155", MobileVendor::Unicom),
+    ("156", MobileVendor::Unicom),
+    ("157", MobileVendor::Mobile),
+    ("158", MobileVendor::Mobile),
+    ("159", MobileVendor::Mobile),
+    ("162", MobileVendor::Telecom), // 虚拟运营商
+    ("165", MobileVendor::Mobile),  // 虚拟运营商
+    ("166", MobileVendor::Unicom),
+    ("167", MobileVendor::Unicom),   // 虚拟运营商
+    ("1700", MobileVendor::Telecom), // 虚拟运营商
+    ("1701", MobileVendor::Telecom), // 虚拟运营商
+    ("1702", MobileVendor::Telecom), // 虚拟运营商
+    ("1703", MobileVendor::Mobile),  // 虚拟运营商
+    ("1704", MobileVendor::Unicom),  // 虚拟运营商
+    ("1705", MobileVendor::Mobile),  // 虚拟运营商
+    ("1706", MobileVendor::Mobile),  // 虚拟运营商
+    ("1707", MobileVendor::Unicom),  // 虚拟运营商
+    ("1708", MobileVendor::Unicom),  // 虚拟运营商
+    ("1709", MobileVendor::Unicom),  // 虚拟运营商
+    ("171", MobileVendor::Unicom),   // 虚拟运营商
+    ("173", MobileVendor::Telecom),
+    ("174", MobileVendor::Telecom), // 卫星电话
+    ("175", MobileVendor::Unicom),
+    ("176", MobileVendor::Unicom),
+    ("177", MobileVendor::Telecom),
+    ("178", MobileVendor::Mobile),
+    ("180", MobileVendor::Telecom),
+    ("181", MobileVendor::Telecom),
+    ("182", MobileVendor::Mobile),
+    ("183", MobileVendor::Mobile),
+    ("184", MobileVendor::Mobile),
+    ("185", MobileVendor::Unicom),
+    ("186", MobileVendor::Unicom),
+    ("187", MobileVendor::Mobile),
+    ("188", MobileVendor::Mobile),
+    ("189", MobileVendor::Telecom),
+    ("190", MobileVendor::Telecom),
+    ("191", MobileVendor::Telecom),
+    ("192", MobileVendor::Cbn),
+    ("193", MobileVendor::Telecom),
+    ("195", MobileVendor::Mobile),
+    ("196", MobileVendor::Unicom),
+    ("197", MobileVendor::Mobile),
+    ("198", MobileVendor::Mobile),
+    ("199", MobileVendor::Telecom),
+];
+
+/// 11 位的物联网号段，不属于手机号段，按字典序排列
+pub(crate) const IOT_SEGMENTS: [&str; 3] = ["146", "148", "172"];
+
+/// 虚拟运营商号段，按字典序排列
+pub(crate) const VIRTUAL_SEGMENTS: [&str; 5] = ["162", "165", "167", "170", "171"];
+
+/// 卫星电话号段，按字典序排列
+pub(crate) const SATELLITE_SEGMENTS: [&str; 2] = ["1349", "1749"];
@@ -0,0 +1,540 @@
+//! 解析后的电话号码
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::str::FromStr;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    classify_region, is_satellite, join_extension, safe_slice, segment_range, split_extension,
+    try_detect_type, PhoneError, PhoneType, Region,
+};
+
+/// 解析后的电话号码
+///
+/// 解析时会完成号码的标准化、类型判断、号段及分机号的提取，之后的查询无需再次匹配正则
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone::{PhoneNumber, PhoneType};
+///
+/// let number = PhoneNumber::parse("+8601012345678-1234").unwrap();
+///
+/// assert_eq!("01012345678", number.number());
+/// assert_eq!(&PhoneType::Tel, number.phone_type());
+/// assert_eq!("010", number.segment());
+/// assert_eq!(Some("1234"), number.extension());
+/// ```
+///
+/// 相等、哈希及排序均基于标准格式的号码及分机号，
+/// 因此 `+8613800138000`、`8613800138000`、`13800138000` 解析后相等，可以直接作为 `HashMap` 的键
+#[derive(Clone, Debug)]
+pub struct PhoneNumber {
+    number: String,
+    phone_type: PhoneType,
+    segment: Range<usize>,
+    extension: Option<String>,
+}
+
+impl PhoneNumber {
+    /// 按国际区号、区号、号码、分机号等部分构建号码
+    pub fn builder() -> PhoneNumberBuilder {
+        PhoneNumberBuilder::default()
+    }
+
+    /// 解析号码，不是电话号码时返回错误
+    ///
+    /// `+852`、`852` 等形式的港澳台号码会被转换为 `00852` 形式，
+    /// 类型为该地区的手机或固定电话，而不是国际长途
+    pub fn parse(number: &str) -> Result<Self, PhoneError> {
+        let region = classify_region(number);
+        let idd;
+        let number = match region {
+            Some(_) if !number.starts_with("00") => {
+                idd = format!("00{}", number.trim_start_matches('+'));
+                idd.as_str()
+            }
+            _ => number,
+        };
+
+        let (detected, standard) = try_detect_type(number)?;
+
+        let (main, extension) = split_extension(standard);
+        let main = match detected {
+            PhoneType::Business => main.replace('-', ""),
+            _ => main.to_owned(),
+        };
+
+        Ok(PhoneNumber {
+            segment: segment_range(&detected, &main),
+            number: main,
+            phone_type: region.map_or(detected, |(_, phone_type)| phone_type),
+            extension: extension.map(ToOwned::to_owned),
+        })
+    }
+
+    /// 中国标准格式的号码，不含分机号
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// 号码类型，港澳台地区号码为 [`PhoneType::Mobile`] 或 [`PhoneType::Tel`]，见 [`classify_region`]
+    pub fn phone_type(&self) -> &PhoneType {
+        &self.phone_type
+    }
+
+    /// 号段：手机为前 7 位，固定电话为区号，国际长途及港澳台号码为国家代码部分，服务号码为完整号码
+    pub fn segment(&self) -> &str {
+        safe_slice(&self.number, self.segment.start, self.segment.end)
+    }
+
+    /// 分机号
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// 忽略分机号比较两个号码是否相同，如 `01012345678-1234` 与 `01012345678`
+    pub fn eq_ignoring_extension(&self, other: &PhoneNumber) -> bool {
+        self.number == other.number
+    }
+
+    /// 港澳台地区号码所属的地区，其它号码返回 `None`
+    pub fn region(&self) -> Option<Region> {
+        classify_region(&self.number).map(|(region, _)| region)
+    }
+
+    /// 是否是卫星电话号码，见 [`is_satellite`]
+    pub fn is_satellite(&self) -> bool {
+        self.phone_type == PhoneType::Mobile && is_satellite(&self.number)
+    }
+}
+
+/// [`PhoneNumber`] 的构建器，适用于各部分分开填写的表单等场景
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone::{PhoneNumber, PhoneType};
+///
+/// let number = PhoneNumber::builder()
+///     .country_code("+86")
+///     .area_code("10")
+///     .subscriber("12345678")
+///     .extension("1234")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!("01012345678", number.number());
+/// assert_eq!(Some("1234"), number.extension());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PhoneNumberBuilder {
+    country_code: Option<String>,
+    area_code: Option<String>,
+    subscriber: String,
+    extension: Option<String>,
+}
+
+impl PhoneNumberBuilder {
+    /// 国际区号，如 `86`、`+852`，不设置或为 `86` 时视为国内号码
+    pub fn country_code(mut self, country_code: impl Into<String>) -> Self {
+        self.country_code = Some(country_code.into());
+        self
+    }
+
+    /// 区号，如 `010`、`10`，国内号码缺少开头的 `0` 时会自动补全
+    pub fn area_code(mut self, area_code: impl Into<String>) -> Self {
+        self.area_code = Some(area_code.into());
+        self
+    }
+
+    /// 号码，如手机号、固定电话的本地号码
+    pub fn subscriber(mut self, subscriber: impl Into<String>) -> Self {
+        self.subscriber = subscriber.into();
+        self
+    }
+
+    /// 分机号
+    pub fn extension(mut self, extension: impl Into<String>) -> Self {
+        self.extension = Some(extension.into());
+        self
+    }
+
+    /// 拼接各部分并解析，不是电话号码时返回错误
+    pub fn build(&self) -> Result<PhoneNumber, PhoneError> {
+        let country_code = self
+            .country_code
+            .as_deref()
+            .map(|code| code.trim_start_matches(['+', '0']))
+            .filter(|code| !code.is_empty() && *code != "86");
+        let area_code = self.area_code.as_deref().unwrap_or_default();
+
+        let mut number = match country_code {
+            Some(code) => format!("00{}{}{}", code, area_code, self.subscriber),
+            None if area_code.is_empty() || area_code.starts_with('0') => {
+                format!("{}{}", area_code, self.subscriber)
+            }
+            None => format!("0{}{}", area_code, self.subscriber),
+        };
+
+        if let Some(extension) = self.extension.as_deref().filter(|e| !e.is_empty()) {
+            number = join_extension(&number, Some(extension));
+        }
+
+        PhoneNumber::parse(&number)
+    }
+}
+
+impl PartialEq for PhoneNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.number == other.number && self.extension == other.extension
+    }
+}
+
+impl Eq for PhoneNumber {}
+
+impl Hash for PhoneNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.number.hash(state);
+        self.extension.hash(state);
+    }
+}
+
+impl Ord for PhoneNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.number
+            .cmp(&other.number)
+            .then_with(|| self.extension.cmp(&other.extension))
+    }
+}
+
+impl PartialOrd for PhoneNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = PhoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PhoneNumber::parse(s)
+    }
+}
+
+impl TryFrom<&str> for PhoneNumber {
+    type Error = PhoneError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        PhoneNumber::parse(value)
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = PhoneError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PhoneNumber::parse(&value)
+    }
+}
+
+impl Display for PhoneNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&join_extension(&self.number, self.extension()))
+    }
+}
+
+/// 序列化为中国标准格式的号码，有分机号时以 `-` 连接
+impl Serialize for PhoneNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+struct PhoneNumberVisitor;
+
+impl<'de> Visitor<'de> for PhoneNumberVisitor {
+    type Value = PhoneNumber;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("反序列化失败，值应该为合法的电话号码.")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        PhoneNumber::parse(v).map_err(E::custom)
+    }
+}
+
+/// 反序列化时会校验号码，不是电话号码时返回错误
+impl<'de> Deserialize<'de> for PhoneNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PhoneNumberVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_mobile() {
+        let number = PhoneNumber::parse("+8613800138000").unwrap();
+
+        assert_eq!("13800138000", number.number());
+        assert_eq!(&PhoneType::Mobile, number.phone_type());
+        assert_eq!("1380013", number.segment());
+        assert_eq!(None, number.extension());
+        assert!(!number.is_satellite());
+        assert!(PhoneNumber::parse("17490123456").unwrap().is_satellite());
+    }
+
+    #[test]
+    fn test_parse_telephone() {
+        let number = PhoneNumber::parse("075512345678,1234").unwrap();
+
+        assert_eq!("075512345678", number.number());
+        assert_eq!(&PhoneType::Tel, number.phone_type());
+        assert_eq!("0755", number.segment());
+        assert_eq!(Some("1234"), number.extension());
+
+        let number = PhoneNumber::parse("01012345678").unwrap();
+
+        assert_eq!("010", number.segment());
+        assert_eq!(None, number.extension());
+    }
+
+    #[test]
+    fn test_parse_service_and_idd() {
+        let number = PhoneNumber::parse("10086").unwrap();
+
+        assert_eq!(&PhoneType::Service, number.phone_type());
+        assert_eq!("10086", number.segment());
+
+        let number = PhoneNumber::parse("008512345678").unwrap();
+
+        assert_eq!(&PhoneType::Idd, number.phone_type());
+        assert_eq!("8512", number.segment());
+        assert_eq!(None, number.region());
+
+        let number = PhoneNumber::parse("+85261234567").unwrap();
+
+        assert_eq!(&PhoneType::Mobile, number.phone_type());
+        assert_eq!("0085261234567", number.number());
+        assert_eq!("852", number.segment());
+        assert_eq!(Some(Region::HongKong), number.region());
+        assert_eq!(PhoneNumber::parse("0085261234567"), Ok(number));
+
+        let number = PhoneNumber::parse("0088621234567").unwrap();
+
+        assert_eq!(&PhoneType::Tel, number.phone_type());
+        assert_eq!("886", number.segment());
+        assert_eq!(Some(Region::Taiwan), number.region());
+    }
+
+    #[test]
+    fn test_parse_business() {
+        let number = PhoneNumber::parse("400-123-4567").unwrap();
+
+        assert_eq!("4001234567", number.number());
+        assert_eq!(&PhoneType::Business, number.phone_type());
+        assert_eq!("400", number.segment());
+        assert_eq!(PhoneNumber::parse("4001234567"), Ok(number));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(Err(PhoneError::Empty), PhoneNumber::parse(""));
+        assert_eq!(
+            Err(PhoneError::Invalid(String::from("12"))),
+            PhoneNumber::parse("12")
+        );
+        assert_eq!(
+            Err(PhoneError::Invalid(String::from("电话号码"))),
+            PhoneNumber::parse("电话号码")
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let number = PhoneNumber::builder()
+            .subscriber("13800138000")
+            .build()
+            .unwrap();
+
+        assert_eq!(PhoneNumber::parse("13800138000"), Ok(number));
+
+        let number = PhoneNumber::builder()
+            .country_code("86")
+            .subscriber("13800138000")
+            .build()
+            .unwrap();
+
+        assert_eq!(&PhoneType::Mobile, number.phone_type());
+
+        let number = PhoneNumber::builder()
+            .area_code("0755")
+            .subscriber("12345678")
+            .extension("1234")
+            .build()
+            .unwrap();
+
+        assert_eq!("075512345678-1234", number.to_string());
+
+        let number = PhoneNumber::builder()
+            .country_code("+852")
+            .subscriber("12345678")
+            .build()
+            .unwrap();
+
+        assert_eq!(&PhoneType::Idd, number.phone_type());
+        assert_eq!("0085212345678", number.number());
+        assert_eq!("852", number.segment());
+    }
+
+    #[test]
+    fn test_builder_invalid() {
+        assert_eq!(Err(PhoneError::Empty), PhoneNumber::builder().build());
+        assert_eq!(
+            Err(PhoneError::Invalid(String::from("010123"))),
+            PhoneNumber::builder()
+                .area_code("010")
+                .subscriber("123")
+                .build()
+        );
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        let numbers: HashSet<PhoneNumber> = ["+8613800138000", "8613800138000", "13800138000"]
+            .into_iter()
+            .map(|number| number.parse().unwrap())
+            .collect();
+
+        assert_eq!(1, numbers.len());
+        assert!(numbers.contains(&"013800138000".parse().unwrap()));
+
+        assert_ne!(
+            PhoneNumber::parse("01012345678-1234"),
+            PhoneNumber::parse("01012345678-5678")
+        );
+    }
+
+    #[test]
+    fn test_eq_ignoring_extension() {
+        let number = PhoneNumber::parse("01012345678-1234").unwrap();
+        let main = PhoneNumber::parse("01012345678").unwrap();
+
+        assert_eq!(Some("1234"), number.extension());
+        assert_ne!(number, main);
+        assert!(number.eq_ignoring_extension(&main));
+        assert!(number.eq_ignoring_extension(&PhoneNumber::parse("+8601012345678,5678").unwrap()));
+        assert!(!number.eq_ignoring_extension(&PhoneNumber::parse("01012345679-1234").unwrap()));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut numbers: Vec<PhoneNumber> = [
+            "18600138000",
+            "+8613800138000",
+            "01012345678-5678",
+            "01012345678",
+        ]
+        .into_iter()
+        .map(|number| number.parse().unwrap())
+        .collect();
+
+        numbers.sort();
+
+        assert_eq!(
+            vec![
+                "01012345678",
+                "01012345678-5678",
+                "13800138000",
+                "18600138000"
+            ],
+            numbers.iter().map(ToString::to_string).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let number: PhoneNumber = "+8613800138000".parse().unwrap();
+
+        assert_eq!(PhoneNumber::parse("13800138000"), Ok(number));
+        assert_eq!(Err(PhoneError::Empty), "".parse::<PhoneNumber>());
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(
+            PhoneNumber::parse("13800138000"),
+            PhoneNumber::try_from("+8613800138000")
+        );
+        assert_eq!(
+            PhoneNumber::parse("01012345678-1234"),
+            PhoneNumber::try_from(String::from("01012345678-1234"))
+        );
+
+        assert_eq!(Err(PhoneError::Empty), PhoneNumber::try_from(""));
+        assert_eq!(
+            Err(PhoneError::Invalid(String::from("12"))),
+            PhoneNumber::try_from(String::from("12"))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "13800138000",
+            PhoneNumber::parse("+8613800138000").unwrap().to_string()
+        );
+        assert_eq!(
+            "075512345678-1234",
+            format!("{}", PhoneNumber::parse("075512345678,1234").unwrap())
+        );
+
+        let number = PhoneNumber::parse("+8601012345678-1234").unwrap();
+
+        assert_eq!(Ok(number.clone()), number.to_string().parse());
+    }
+
+    #[test]
+    fn test_json() {
+        let number: PhoneNumber = serde_json::from_str("\"+8613800138000\"").unwrap();
+
+        assert_eq!(PhoneNumber::parse("13800138000"), Ok(number.clone()));
+        assert_eq!("\"13800138000\"", serde_json::to_string(&number).unwrap());
+
+        let number: PhoneNumber = serde_json::from_str("\"075512345678,1234\"").unwrap();
+
+        assert_eq!(
+            "\"075512345678-1234\"",
+            serde_json::to_string(&number).unwrap()
+        );
+        assert_eq!(
+            number,
+            serde_json::from_str(&serde_json::to_string(&number).unwrap()).unwrap()
+        );
+
+        let err = serde_json::from_str::<PhoneNumber>("\"12\"").unwrap_err();
+
+        assert!(err.to_string().contains("12 不是正常的电话号码"));
+        assert!(serde_json::from_str::<PhoneNumber>("13800138000").is_err());
+    }
+}
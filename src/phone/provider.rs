@@ -0,0 +1,176 @@
+//! 号段信息的数据源
+
+use std::future::Future;
+
+use serde::Deserialize;
+
+use super::{find_mobile_segment, get_segment, MobileVendor, PhoneType};
+
+/// 号段信息
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct SegmentInfo {
+    /// 运营商
+    pub vendor: MobileVendor,
+    /// 省份，数据源不提供时为 `None`
+    #[serde(default)]
+    pub province: Option<String>,
+    /// 城市，数据源不提供时为 `None`
+    #[serde(default)]
+    pub city: Option<String>,
+}
+
+/// 号段信息的数据源
+///
+/// 默认使用内置的 [`EmbeddedProvider`]，拥有商业归属地数据或内部数据库时，
+/// 可以实现该 trait 并通过 [`lookup_with`] 使用
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone::{lookup_with, MobileVendor, SegmentInfo, SegmentProvider};
+///
+/// struct Internal;
+///
+/// impl SegmentProvider for Internal {
+///     fn lookup(&self, segment: &str) -> Option<SegmentInfo> {
+///         (segment == "1380013").then(|| SegmentInfo {
+///             vendor: MobileVendor::Mobile,
+///             province: Some(String::from("北京")),
+///             city: Some(String::from("北京")),
+///         })
+///     }
+/// }
+///
+/// let info = lookup_with(&Internal, "+8613800138000").unwrap();
+///
+/// assert_eq!(Some("北京"), info.city.as_deref());
+/// ```
+pub trait SegmentProvider {
+    /// 查询号段信息，`segment` 为 [`get_segment`] 返回的手机号码前 7 位，如 `1380013`
+    fn lookup(&self, segment: &str) -> Option<SegmentInfo>;
+}
+
+/// 内置的号段数据源，仅包含运营商信息
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmbeddedProvider;
+
+impl SegmentProvider for EmbeddedProvider {
+    fn lookup(&self, segment: &str) -> Option<SegmentInfo> {
+        find_mobile_segment(segment).map(|(_, vendor)| SegmentInfo {
+            vendor: vendor.clone(),
+            province: None,
+            city: None,
+        })
+    }
+}
+
+/// 通过指定的数据源查询手机号码的号段信息，非手机号码或数据源中不存在时返回 `None`
+pub fn lookup_with<P: SegmentProvider + ?Sized>(provider: &P, number: &str) -> Option<SegmentInfo> {
+    match get_segment(number) {
+        (PhoneType::Mobile, segment) => provider.lookup(segment),
+        _ => None,
+    }
+}
+
+/// 异步的号段信息数据源，如通过 HTTP 接口查询的 `HttpProvider`（需启用 `http` feature）
+///
+/// 所有的 [`SegmentProvider`] 都自动实现了该 trait，可以与异步数据源一起通过 [`lookup_with_async`] 使用
+///
+/// # Example
+///
+/// ```
+/// use yansongda_utils::phone::{lookup_with_async, EmbeddedProvider, MobileVendor};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let info = lookup_with_async(&EmbeddedProvider, "13800138000").await.unwrap();
+///
+/// assert_eq!(MobileVendor::Mobile, info.vendor);
+/// # }
+/// ```
+pub trait AsyncSegmentProvider {
+    /// 查询号段信息，`segment` 为 [`get_segment`] 返回的手机号码前 7 位，如 `1380013`
+    fn lookup_async(&self, segment: &str) -> impl Future<Output = Option<SegmentInfo>> + Send;
+}
+
+impl<P: SegmentProvider + Sync + ?Sized> AsyncSegmentProvider for P {
+    fn lookup_async(&self, segment: &str) -> impl Future<Output = Option<SegmentInfo>> + Send {
+        std::future::ready(self.lookup(segment))
+    }
+}
+
+/// 通过指定的异步数据源查询手机号码的号段信息，非手机号码或数据源中不存在时返回 `None`
+pub async fn lookup_with_async<P: AsyncSegmentProvider + ?Sized>(
+    provider: &P,
+    number: &str,
+) -> Option<SegmentInfo> {
+    match get_segment(number) {
+        (PhoneType::Mobile, segment) => provider.lookup_async(segment).await,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixed;
+
+    impl SegmentProvider for Fixed {
+        fn lookup(&self, _segment: &str) -> Option<SegmentInfo> {
+            Some(SegmentInfo {
+                vendor: MobileVendor::Cbn,
+                province: Some(String::from("广东")),
+                city: Some(String::from("深圳")),
+            })
+        }
+    }
+
+    #[test]
+    fn test_embedded_provider() {
+        assert_eq!(
+            Some(SegmentInfo {
+                vendor: MobileVendor::Unicom,
+                province: None,
+                city: None,
+            }),
+            lookup_with(&EmbeddedProvider, "18600138000")
+        );
+        assert_eq!(
+            Some(MobileVendor::Telecom),
+            EmbeddedProvider.lookup("1349012").map(|info| info.vendor)
+        );
+        assert_eq!(None, EmbeddedProvider.lookup("1540013"));
+    }
+
+    #[test]
+    fn test_custom_provider() {
+        let provider: Box<dyn SegmentProvider> = Box::new(Fixed);
+
+        assert_eq!(
+            Some(String::from("深圳")),
+            lookup_with(provider.as_ref(), "+8613800138000").and_then(|info| info.city)
+        );
+        assert_eq!(None, lookup_with(&Fixed, "01012345678"));
+        assert_eq!(None, lookup_with(&Fixed, "10086"));
+    }
+
+    #[tokio::test]
+    async fn test_async_provider() {
+        assert_eq!(
+            lookup_with(&EmbeddedProvider, "18600138000"),
+            lookup_with_async(&EmbeddedProvider, "18600138000").await
+        );
+        assert_eq!(
+            Some(String::from("深圳")),
+            lookup_with_async(&Fixed, "+8613800138000")
+                .await
+                .and_then(|info| info.city)
+        );
+        assert_eq!(None, lookup_with_async(&Fixed, "01012345678").await);
+        assert_eq!(
+            EmbeddedProvider.lookup("1380013"),
+            EmbeddedProvider.lookup_async("1380013").await
+        );
+    }
+}
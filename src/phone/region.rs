@@ -0,0 +1,244 @@
+//! 港澳台地区的电话号码
+
+use std::fmt::Formatter;
+
+use serde::de::{Error, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{is_within_max_input_len, PhoneType};
+use crate::regex;
+
+/// 中国的地区，用于区分中国大陆及港澳台
+///
+/// 序列化为 `CN`、`HK`、`MO`、`TW`，反序列化时不区分大小写
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Region {
+    /// 中国大陆，[`classify_region`] 不会返回该地区
+    Mainland,
+    /// 中国香港
+    HongKong,
+    /// 中国澳门
+    Macau,
+    /// 中国台湾
+    Taiwan,
+}
+
+impl Region {
+    /// ISO 3166-1 二位字母代码，如 `HK`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Region::Mainland => "CN",
+            Region::HongKong => "HK",
+            Region::Macau => "MO",
+            Region::Taiwan => "TW",
+        }
+    }
+
+    /// 国际区号，如 `852`
+    pub fn country_code(&self) -> &'static str {
+        match self {
+            Region::Mainland => "86",
+            Region::HongKong => "852",
+            Region::Macau => "853",
+            Region::Taiwan => "886",
+        }
+    }
+
+    /// 地区名称，如 `香港`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Region::Mainland => "中国大陆",
+            Region::HongKong => "香港",
+            Region::Macau => "澳门",
+            Region::Taiwan => "台湾",
+        }
+    }
+}
+
+impl Serialize for Region {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+struct RegionVisitor;
+
+impl<'de> Visitor<'de> for RegionVisitor {
+    type Value = Region;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("反序列化失败，值应该为 CN/HK/MO/TW 之一.")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        [
+            Region::Mainland,
+            Region::HongKong,
+            Region::Macau,
+            Region::Taiwan,
+        ]
+        .into_iter()
+        .find(|region| region.code().eq_ignore_ascii_case(v))
+        .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Region {
+    fn deserialize<D>(deserializer: D) -> Result<Region, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(RegionVisitor)
+    }
+}
+
+/// 判断港澳台地区号码的地区及类型，号码须带有 `+`、`00` 或直接以国际区号开头，
+/// 如 `+85261234567`、`0085321234567`、`886912345678`；不是港澳台地区的号码返回 `None`
+///
+/// 类型仅区分 [`PhoneType::Mobile`] 与 [`PhoneType::Tel`]
+///
+/// ```
+/// use yansongda_utils::phone::{classify_region, PhoneType, Region};
+///
+/// assert_eq!(Some((Region::HongKong, PhoneType::Mobile)), classify_region("+85261234567"));
+/// assert_eq!(Some((Region::Taiwan, PhoneType::Tel)), classify_region("0088621234567"));
+/// assert_eq!(None, classify_region("13800138000"));
+/// ```
+pub fn classify_region(number: &str) -> Option<(Region, PhoneType)> {
+    if !is_within_max_input_len(number) {
+        return None;
+    }
+
+    let rest = number
+        .strip_prefix('+')
+        .or_else(|| number.strip_prefix("00"))
+        .unwrap_or(number);
+
+    if let Some(national) = rest.strip_prefix("852") {
+        return match national {
+            n if regex!(r"^[4-9]\d{7}$").is_match(n) => Some((Region::HongKong, PhoneType::Mobile)),
+            n if regex!(r"^[23]\d{7}$").is_match(n) => Some((Region::HongKong, PhoneType::Tel)),
+            _ => None,
+        };
+    }
+
+    if let Some(national) = rest.strip_prefix("853") {
+        return match national {
+            n if regex!(r"^6\d{7}$").is_match(n) => Some((Region::Macau, PhoneType::Mobile)),
+            n if regex!(r"^28\d{6}$").is_match(n) => Some((Region::Macau, PhoneType::Tel)),
+            _ => None,
+        };
+    }
+
+    if let Some(national) = rest.strip_prefix("886") {
+        let national = national.strip_prefix('0').unwrap_or(national);
+
+        return match national {
+            n if regex!(r"^9\d{8}$").is_match(n) => Some((Region::Taiwan, PhoneType::Mobile)),
+            n if regex!(r"^[2-8]\d{7,8}$").is_match(n) => Some((Region::Taiwan, PhoneType::Tel)),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phone::tests::assert_json_roundtrip;
+
+    #[test]
+    fn test_classify_region_hong_kong() {
+        assert_eq!(
+            Some((Region::HongKong, PhoneType::Mobile)),
+            classify_region("+85261234567")
+        );
+        assert_eq!(
+            Some((Region::HongKong, PhoneType::Mobile)),
+            classify_region("0085291234567")
+        );
+        assert_eq!(
+            Some((Region::HongKong, PhoneType::Tel)),
+            classify_region("85221234567")
+        );
+        assert_eq!(None, classify_region("+8521234567"));
+        assert_eq!(None, classify_region("+85212345678"));
+    }
+
+    #[test]
+    fn test_classify_region_macau() {
+        assert_eq!(
+            Some((Region::Macau, PhoneType::Mobile)),
+            classify_region("+85361234567")
+        );
+        assert_eq!(
+            Some((Region::Macau, PhoneType::Tel)),
+            classify_region("0085328123456")
+        );
+        assert_eq!(None, classify_region("+85321234567"));
+    }
+
+    #[test]
+    fn test_classify_region_taiwan() {
+        assert_eq!(
+            Some((Region::Taiwan, PhoneType::Mobile)),
+            classify_region("+886912345678")
+        );
+        assert_eq!(
+            Some((Region::Taiwan, PhoneType::Mobile)),
+            classify_region("+8860912345678")
+        );
+        assert_eq!(
+            Some((Region::Taiwan, PhoneType::Tel)),
+            classify_region("0088621234567")
+        );
+        assert_eq!(None, classify_region("+88691234567"));
+    }
+
+    #[test]
+    fn test_classify_region_others() {
+        assert_eq!(None, classify_region("13800138000"));
+        assert_eq!(None, classify_region("+8613800138000"));
+        assert_eq!(None, classify_region(""));
+    }
+
+    #[test]
+    fn test_region() {
+        assert_eq!("852", Region::HongKong.country_code());
+        assert_eq!("澳门", Region::Macau.name());
+        assert_eq!("TW", Region::Taiwan.code());
+    }
+
+    #[test]
+    fn test_region_json() {
+        for (region, json) in [
+            (Region::Mainland, "\"CN\""),
+            (Region::HongKong, "\"HK\""),
+            (Region::Macau, "\"MO\""),
+            (Region::Taiwan, "\"TW\""),
+        ] {
+            assert_eq!(json, serde_json::to_string(&region).unwrap());
+            assert_json_roundtrip(region);
+        }
+
+        assert_eq!(
+            Region::HongKong,
+            serde_json::from_str::<Region>("\"hk\"").unwrap()
+        );
+        assert_eq!(
+            Region::Taiwan,
+            serde_json::from_str::<Region>("\"Tw\"").unwrap()
+        );
+        assert!(serde_json::from_str::<Region>("\"US\"").is_err());
+        assert!(serde_json::from_str::<Region>("\"\"").is_err());
+        assert!(serde_json::from_str::<Region>("852").is_err());
+    }
+}
@@ -0,0 +1,41 @@
+//! 95、96 开头的服务号码
+
+/// 95、96 开头的服务号码及其所属机构，按字典序排列以便二分查找
+pub(crate) const SERVICE_CODES: [(&str, &str); 36] = [
+    ("95017", "微信支付"),
+    ("95188", "支付宝"),
+    ("95311", "中通快递"),
+    ("95338", "顺丰速运"),
+    ("95500", "中国太平洋保险"),
+    ("95508", "广发银行"),
+    ("95511", "平安集团"),
+    ("95516", "中国银联"),
+    ("95518", "中国人保财险"),
+    ("95519", "中国人寿"),
+    ("95522", "泰康人寿"),
+    ("95526", "北京银行"),
+    ("95528", "浦发银行"),
+    ("95530", "中国东方航空"),
+    ("95533", "中国建设银行"),
+    ("95539", "中国南方航空"),
+    ("95543", "申通快递"),
+    ("95546", "韵达快递"),
+    ("95554", "圆通速递"),
+    ("95555", "招商银行"),
+    ("95558", "中信银行"),
+    ("95559", "交通银行"),
+    ("95561", "兴业银行"),
+    ("95566", "中国银行"),
+    ("95567", "新华保险"),
+    ("95568", "中国民生银行"),
+    ("95577", "华夏银行"),
+    ("95580", "中国邮政储蓄银行"),
+    ("95583", "中国国际航空"),
+    ("95588", "中国工商银行"),
+    ("95589", "中国太平保险"),
+    ("95595", "中国光大银行"),
+    ("95598", "国家电网"),
+    ("95599", "中国农业银行"),
+    ("96110", "反诈预警劝阻专线"),
+    ("96119", "火灾隐患举报"),
+];
@@ -0,0 +1,32 @@
+//! 全国统一的特服及公共服务短号码
+
+/// 全国统一的特服及公共服务短号码及其用途，按字典序排列以便二分查找
+pub(crate) const SHORT_CODES: [(&str, &str); 27] = [
+    ("110", "匪警"),
+    ("112", "紧急呼叫"),
+    ("114", "查号台"),
+    ("119", "火警"),
+    ("120", "急救中心"),
+    ("121", "天气预报"),
+    ("12110", "短信报警"),
+    ("12117", "报时服务"),
+    ("12121", "天气预报"),
+    ("122", "交通事故报警"),
+    ("12306", "铁路客服"),
+    ("12315", "消费者投诉举报"),
+    ("12318", "文化市场举报"),
+    ("12319", "城建服务"),
+    ("12320", "卫生健康热线"),
+    ("12329", "住房公积金"),
+    ("12333", "人社服务"),
+    ("12345", "政务服务便民热线"),
+    ("12348", "法律服务"),
+    ("12358", "价格举报"),
+    ("12365", "质量监督"),
+    ("12369", "环保举报"),
+    ("12377", "违法和不良信息举报"),
+    ("12388", "纪检监察举报"),
+    ("12395", "水上遇险求救"),
+    ("12580", "综合信息服务"),
+    ("999", "红十字急救"),
+];
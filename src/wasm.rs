@@ -0,0 +1,35 @@
+//! 通过 `wasm-bindgen` 导出的工具函数
+//!
+//! 编译方式：
+//!
+//! ```shell
+//! cargo build --target wasm32-unknown-unknown --features wasm
+//! ```
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::phone;
+
+/// 给定的号码是否是正常的手机号
+#[wasm_bindgen(js_name = isMobile)]
+pub fn is_mobile(number: &str) -> bool {
+    phone::is_mobile(number)
+}
+
+/// 给定的号码是否是正常的电话号码
+#[wasm_bindgen(js_name = isPhone)]
+pub fn is_phone(number: &str) -> bool {
+    phone::is_phone(number)
+}
+
+/// 判断号码类型，返回类型名称，如 `MOBILE`
+#[wasm_bindgen(js_name = classify)]
+pub fn classify(number: &str) -> String {
+    phone::classify(number).as_str().to_owned()
+}
+
+/// 将号码转换为中国标准格式，即不带 +、+86、86、0 等形式
+#[wasm_bindgen(js_name = toStandardFormat)]
+pub fn to_standard_format(number: &str) -> String {
+    phone::to_standard_format(number).to_owned()
+}